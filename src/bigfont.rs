@@ -0,0 +1,358 @@
+
+use crate::{
+    Hd44780, Hd44780Error,
+    characters::{CharSet, CustomFont5x8},
+    framebuffer::MAX_CELLS,
+    interface::InterfaceTrait,
+    types::{DisplayTypeFont5x8, DisplayTypeTrait},
+};
+
+#[cfg(feature="async")]
+use crate::{Hd44780Async, interface::AsyncInterfaceTrait};
+
+
+// Every big character is a few columns of strokes and bars,
+// which are drawn with the pieces available for the selected height.
+const UPPER: u8 = 0b0000_0001; // vertical stroke through the upper half
+const LOWER: u8 = 0b0000_0010; // vertical stroke through the lower half
+const TOP: u8 = 0b0000_0100;
+const MID: u8 = 0b0000_1000;
+const BOTTOM: u8 = 0b0001_0000;
+const DOT: u8 = 0b0010_0000;
+
+const BLANK: u8 = b' ';
+// columns of a character missing in the font
+const MISSING: &[u8] = &[0, 0, 0];
+
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Piece {
+    Full,
+    Top,
+    Bottom,
+    TopMid,    // top bar and the upper half of the middle bar (2 rows)
+    MidUpper,  // upper half of the middle bar (2 and 4 rows)
+    MidLower,  // lower half of the middle bar (2 and 4 rows)
+    MidBottom, // lower half of the middle bar and bottom bar (2 rows)
+    Mid,       // middle bar (3 rows)
+    UpperHalf, // stroke ending in the middle bar (3 rows)
+    LowerHalf, // stroke starting at the middle bar (3 rows)
+    Dot,
+}
+
+impl Piece {
+    const fn rows(self) -> [u8; 8] {
+        match self {
+            Piece::Full      => [0x1f, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f],
+            Piece::Top       => [0x1f, 0x1f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+            Piece::Bottom    => [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1f, 0x1f],
+            Piece::TopMid    => [0x1f, 0x1f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1f],
+            Piece::MidUpper  => [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1f],
+            Piece::MidLower  => [0x1f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+            Piece::MidBottom => [0x1f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1f, 0x1f],
+            Piece::Mid       => [0x00, 0x00, 0x00, 0x1f, 0x1f, 0x00, 0x00, 0x00],
+            Piece::UpperHalf => [0x1f, 0x1f, 0x1f, 0x1f, 0x1f, 0x00, 0x00, 0x00],
+            Piece::LowerHalf => [0x00, 0x00, 0x00, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f],
+            Piece::Dot       => [0x00, 0x00, 0x0e, 0x0e, 0x0e, 0x00, 0x00, 0x00],
+        }
+    }
+}
+
+const PIECES_2: [Piece; 8] = [
+    Piece::Full, Piece::Top, Piece::Bottom, Piece::TopMid,
+    Piece::MidUpper, Piece::MidLower, Piece::MidBottom, Piece::Dot,
+];
+const PIECES_3: [Piece; 7] = [
+    Piece::Full, Piece::Top, Piece::Bottom, Piece::Mid,
+    Piece::UpperHalf, Piece::LowerHalf, Piece::Dot,
+];
+const PIECES_4: [Piece; 6] = [
+    Piece::Full, Piece::Top, Piece::Bottom, Piece::MidUpper,
+    Piece::MidLower, Piece::Dot,
+];
+
+const fn charset(name: &'static str, pieces: &[Piece]) -> CharSet {
+    let mut glyphs = [[0; 8]; 8];
+    let mut i = 0;
+    while i < pieces.len() {
+        glyphs[i] = pieces[i].rows();
+        i += 1;
+    }
+    CharSet::new(name, glyphs.split_at(pieces.len()).0)
+}
+
+const CHARSET_2: CharSet = charset("big font 2 rows", &PIECES_2);
+const CHARSET_3: CharSet = charset("big font 3 rows", &PIECES_3);
+const CHARSET_4: CharSet = charset("big font 4 rows", &PIECES_4);
+
+
+// Columns of `c`, letters are drawn in the style of the digits and O, S and Z share their shape.
+// Diagonal strokes are stepped, X steps from the bars in its corners to the middle bar.
+fn columns(c: char) -> Option<&'static [u8]> {
+    let columns: &'static [u8] = match c.to_ascii_uppercase() {
+        '0' | 'O' => &[UPPER|LOWER, TOP|BOTTOM, UPPER|LOWER],
+        '1' => &[TOP|BOTTOM, UPPER|LOWER, BOTTOM],
+        '2' | 'Z' => &[TOP|MID|LOWER, TOP|MID|BOTTOM, UPPER|MID|BOTTOM],
+        '3' => &[TOP|MID|BOTTOM, TOP|MID|BOTTOM, UPPER|LOWER],
+        '4' => &[UPPER|MID, MID, UPPER|LOWER],
+        '5' | 'S' => &[UPPER|MID|BOTTOM, TOP|MID|BOTTOM, TOP|MID|LOWER],
+        '6' => &[UPPER|LOWER, TOP|MID|BOTTOM, TOP|MID|LOWER],
+        '7' => &[TOP, TOP, UPPER|LOWER],
+        '8' => &[UPPER|LOWER, TOP|MID|BOTTOM, UPPER|LOWER],
+        '9' => &[UPPER|MID|BOTTOM, TOP|MID|BOTTOM, UPPER|LOWER],
+        'A' => &[UPPER|LOWER, TOP|MID, UPPER|LOWER],
+        'B' => &[UPPER|LOWER, MID|BOTTOM, MID|LOWER],
+        'C' => &[UPPER|LOWER, TOP|BOTTOM, TOP|BOTTOM],
+        'D' => &[MID|LOWER, MID|BOTTOM, UPPER|LOWER],
+        'E' => &[UPPER|LOWER, TOP|MID|BOTTOM, TOP|MID|BOTTOM],
+        'F' => &[UPPER|LOWER, TOP|MID, TOP|MID],
+        'G' => &[UPPER|LOWER, TOP|BOTTOM, TOP|LOWER],
+        'H' => &[UPPER|LOWER, MID, UPPER|LOWER],
+        'I' => &[TOP|BOTTOM, UPPER|LOWER, TOP|BOTTOM],
+        'J' => &[LOWER, BOTTOM, UPPER|LOWER],
+        'K' => &[UPPER|LOWER, MID, TOP|LOWER],
+        'L' => &[UPPER|LOWER, BOTTOM, BOTTOM],
+        'M' => &[UPPER|LOWER, TOP, UPPER|LOWER, TOP, UPPER|LOWER],
+        'N' => &[UPPER|LOWER, UPPER, LOWER, UPPER|LOWER],
+        'P' => &[UPPER|LOWER, TOP|MID, UPPER|MID],
+        'Q' => &[UPPER|LOWER, TOP|BOTTOM, UPPER|LOWER, BOTTOM],
+        'R' => &[UPPER|LOWER, TOP|MID, UPPER|MID, LOWER],
+        'T' => &[TOP, UPPER|LOWER, TOP],
+        'U' => &[UPPER|LOWER, BOTTOM, UPPER|LOWER],
+        'V' => &[UPPER, LOWER, UPPER],
+        'W' => &[UPPER|LOWER, BOTTOM, UPPER|LOWER, BOTTOM, UPPER|LOWER],
+        'X' => &[TOP|BOTTOM, MID, TOP|BOTTOM],
+        'Y' => &[UPPER|MID, MID|LOWER, UPPER|MID],
+        '-' => &[MID, MID],
+        '.' => &[BOTTOM],
+        ':' => &[DOT],
+        ' ' => &[0],
+        _ => return None,
+    };
+    Some(columns)
+}
+
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BigFontSize {
+    Rows2 = 2,
+    Rows3 = 3,
+    Rows4 = 4,
+}
+
+/// Draws digits, letters, `'-'`, `'.'`, `':'` and `' '` over 2, 3 or 4 display rows,
+/// most characters are 3 columns wide and followed by an empty column.
+/// Other characters are not an error, they are drawn as 3 blank columns.
+/// The pieces are loaded into the custom characters as a [`CharSet`] before drawing,
+/// which overwrites other custom characters.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BigFont {
+    size: BigFontSize,
+}
+
+impl BigFont {
+    pub const fn new(size: BigFontSize) -> Self {
+        Self { size }
+    }
+
+    pub const fn rows(&self) -> u8 {
+        self.size as u8
+    }
+
+    /// The custom characters used by this size.
+    pub const fn charset(&self) -> &'static CharSet {
+        match self.size {
+            BigFontSize::Rows2 => &CHARSET_2,
+            BigFontSize::Rows3 => &CHARSET_3,
+            BigFontSize::Rows4 => &CHARSET_4,
+        }
+    }
+
+    /// Number of display columns `text` takes up.
+    pub fn width(&self, text: &str) -> usize {
+        let columns: usize = text.chars().map(|c| columns(c).unwrap_or(MISSING).len() + 1).sum();
+        columns.saturating_sub(1)
+    }
+
+    fn code(&self, piece: Piece) -> u8 {
+        let pieces: &[Piece] = match self.size {
+            BigFontSize::Rows2 => &PIECES_2,
+            BigFontSize::Rows3 => &PIECES_3,
+            BigFontSize::Rows4 => &PIECES_4,
+        };
+        pieces.iter().position(|&p| p == piece).map_or(BLANK, |i| CustomFont5x8::ALL[i] as u8)
+    }
+
+    // character code showing display row `row` of a column
+    fn cell(&self, column: u8, row: u8) -> u8 {
+        let has = |flags: u8| column & flags == flags;
+        let piece = if has(DOT) {
+            let dot = match self.size {
+                BigFontSize::Rows2 => true,
+                BigFontSize::Rows3 => row != 1,
+                BigFontSize::Rows4 => row == 1 || row == 2,
+            };
+            dot.then_some(Piece::Dot)
+        } else {
+            match (self.size, row) {
+                (_, 0) if has(UPPER) => Some(Piece::Full),
+                (BigFontSize::Rows2, 0) => match (has(TOP), has(MID)) {
+                    (true, true) => Some(Piece::TopMid),
+                    (true, false) => Some(Piece::Top),
+                    (false, true) => Some(Piece::MidUpper),
+                    (false, false) => None,
+                },
+                (_, 0) => has(TOP).then_some(Piece::Top),
+                (BigFontSize::Rows2, _) if has(LOWER) => Some(Piece::Full),
+                (BigFontSize::Rows2, _) => match (has(MID), has(BOTTOM)) {
+                    (true, true) => Some(Piece::MidBottom),
+                    (true, false) => Some(Piece::MidLower),
+                    (false, true) => Some(Piece::Bottom),
+                    (false, false) => None,
+                },
+                (BigFontSize::Rows3, 1) => match (has(UPPER), has(LOWER)) {
+                    (true, true) => Some(Piece::Full),
+                    (true, false) => Some(Piece::UpperHalf),
+                    (false, true) => Some(Piece::LowerHalf),
+                    (false, false) => has(MID).then_some(Piece::Mid),
+                },
+                (BigFontSize::Rows4, 1) if has(UPPER) => Some(Piece::Full),
+                (BigFontSize::Rows4, 1) => has(MID).then_some(Piece::MidUpper),
+                (BigFontSize::Rows4, 2) if has(LOWER) => Some(Piece::Full),
+                (BigFontSize::Rows4, 2) => has(MID).then_some(Piece::MidLower),
+                (_, _) if has(LOWER) => Some(Piece::Full),
+                (_, _) => has(BOTTOM).then_some(Piece::Bottom),
+            }
+        };
+        piece.map_or(BLANK, |piece| self.code(piece))
+    }
+
+    // renders display row `row` of `text` into `buf`, returns the number of columns
+    fn render(&self, text: &str, row: u8, buf: &mut [u8]) -> usize {
+        let mut len = 0;
+        for (i, columns) in text.chars().map(|c| columns(c).unwrap_or(MISSING)).enumerate() {
+            let gap = (i > 0).then_some(BLANK);
+            let cells = gap.into_iter().chain(columns.iter().map(|&column| self.cell(column, row)));
+            for cell in cells {
+                if len == buf.len() {
+                    return len;
+                }
+                buf[len] = cell;
+                len += 1;
+            }
+        }
+        len
+    }
+
+    // display columns left from `col` on
+    fn visible<DPTYPE: DisplayTypeTrait>(&self, dp_type: &DPTYPE, row: u8, col: u8) -> Option<usize> {
+        if row.checked_add(self.rows()).is_none_or(|end| end > dp_type.rows()) || col >= dp_type.cols() {
+            return None;
+        }
+        Some(((dp_type.cols() - col) as usize).min(MAX_CELLS))
+    }
+
+    /// Draws `text` with its top left corner at `row`/`col`, clipped at the right edge of the display.
+    /// Characters the font does not have are left blank.
+    pub fn print<INTERFACE>(&self, dp: &mut Hd44780<INTERFACE, DisplayTypeFont5x8>, row: u8, col: u8, text: &str) -> Result<(), Hd44780Error<INTERFACE::Error>>
+    where
+        INTERFACE: InterfaceTrait,
+    {
+        let cols = self.visible(dp.display_type(), row, col).ok_or(Hd44780Error::RowColOutOfRange)?;
+        dp.load_charset(self.charset())?;
+        let mut buf = [BLANK; MAX_CELLS];
+        for r in 0..self.rows() {
+            let len = self.render(text, r, &mut buf[..cols]);
+            dp.position(row + r, col)?;
+            dp.print_bytes(&buf[..len])?;
+        }
+        Ok(())
+    }
+
+    /// Async counterpart of [`BigFont::print`].
+    #[cfg(feature="async")]
+    pub async fn print_async<INTERFACE>(&self, dp: &mut Hd44780Async<INTERFACE, DisplayTypeFont5x8>, row: u8, col: u8, text: &str) -> Result<(), Hd44780Error<INTERFACE::Error>>
+    where
+        INTERFACE: AsyncInterfaceTrait,
+    {
+        let cols = self.visible(dp.display_type(), row, col).ok_or(Hd44780Error::RowColOutOfRange)?;
+        dp.load_charset(self.charset()).await?;
+        let mut buf = [BLANK; MAX_CELLS];
+        for r in 0..self.rows() {
+            let len = self.render(text, r, &mut buf[..cols]);
+            dp.position(row + r, col).await?;
+            dp.print_bytes(&buf[..len]).await?;
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const F: u8 = 0x08;
+    const T: u8 = 0x09;
+    const B: u8 = 0x0a;
+    const S: u8 = BLANK;
+
+    fn rows<const N: usize>(font:BigFont, text:&str) -> [[u8; 8]; N] {
+        let mut rows = [[0; 8]; N];
+        for (row, buf) in rows.iter_mut().enumerate() {
+            let len = font.render(text, row as u8, buf);
+            assert_eq!(len, font.width(text).min(8));
+        }
+        rows
+    }
+
+    #[test]
+    fn render_2_rows() {
+        let font = BigFont::new(BigFontSize::Rows2);
+        assert_eq!(rows::<2>(font, "1-"), [[T, F, S, S, 0x0c, 0x0c, 0, 0], [B, F, B, S, 0x0d, 0x0d, 0, 0]]);
+    }
+
+    #[test]
+    fn render_3_rows() {
+        let font = BigFont::new(BigFontSize::Rows3);
+        assert_eq!(rows::<3>(font, "1-"), [[T, F, S, S, S, S, 0, 0], [S, F, S, S, 0x0b, 0x0b, 0, 0], [B, F, B, S, S, S, 0, 0]]);
+    }
+
+    #[test]
+    fn render_4_rows() {
+        let font = BigFont::new(BigFontSize::Rows4);
+        assert_eq!(rows::<4>(font, "1-"), [
+            [T, F, S, S, S, S, 0, 0],
+            [S, F, S, S, 0x0b, 0x0b, 0, 0],
+            [S, F, S, S, 0x0c, 0x0c, 0, 0],
+            [B, F, B, S, S, S, 0, 0],
+        ]);
+    }
+
+    #[test]
+    fn render_clips_and_blanks_missing_chars() {
+        let font = BigFont::new(BigFontSize::Rows2);
+        assert_eq!(font.width("1?1"), 11);
+        assert_eq!(rows::<2>(font, "1?1"), [[T, F, S, S, S, S, S, S], [B, F, B, S, S, S, S, S]]);
+    }
+
+    #[test]
+    fn letters_have_their_own_shapes() {
+        for a in 'A'..='Z' {
+            for b in (a..='Z').skip(1) {
+                assert_ne!(columns(a).unwrap(), columns(b).unwrap(), "{a} and {b}");
+            }
+        }
+        assert_eq!(columns('O'), columns('0'));
+        assert_eq!(columns('x'), columns('X'));
+        assert_eq!(columns('?'), None);
+    }
+
+    #[test]
+    fn x_steps_from_the_corners_to_the_middle() {
+        let font = BigFont::new(BigFontSize::Rows2);
+        assert_eq!(rows::<2>(font, "X"), [[T, 0x0c, T, 0, 0, 0, 0, 0], [B, 0x0d, B, 0, 0, 0, 0, 0]]);
+        let font = BigFont::new(BigFontSize::Rows3);
+        assert_eq!(rows::<3>(font, "x"), [[T, S, T, 0, 0, 0, 0, 0], [S, 0x0b, S, 0, 0, 0, 0, 0], [B, S, B, 0, 0, 0, 0, 0]]);
+    }
+}
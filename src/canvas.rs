@@ -0,0 +1,255 @@
+
+use crate::{
+    Hd44780, Hd44780Error,
+    characters::CustomFont5x8,
+    framebuffer::flush_row,
+    interface::InterfaceTrait,
+    types::DisplayTypeFont5x8,
+};
+
+#[cfg(feature="async")]
+use crate::{Hd44780Async, framebuffer::flush_row_async, interface::AsyncInterfaceTrait};
+
+
+const SLOTS: usize = 8;
+const EMPTY: [u8; 8] = [0; 8];
+
+// glyph held by every custom character, `None` if unused
+type Slots = [Option<[u8; 8]>; SLOTS];
+
+
+/// Monochrome pixel canvas covering `COLS` x `ROWS` cells, 5x8 pixels each, with its
+/// top left corner at `row`/`col`. Identical tiles share a custom character and empty tiles are
+/// printed as spaces, so any drawing with at most 8 distinct non-empty tiles can be flushed.
+/// The canvas takes up all custom characters and only uploads and prints what changed.
+/// With the `embedded-graphics` feature it is a `DrawTarget` for `BinaryColor`.
+#[derive(Debug, Clone)]
+pub struct Canvas<const COLS: usize, const ROWS: usize> {
+    row: u8,
+    col: u8,
+    tiles: [[[u8; 8]; COLS]; ROWS],
+    // custom characters and codes of the last flush
+    loaded: Slots,
+    shown: [[u8; COLS]; ROWS],
+    synced: bool,
+}
+
+impl<const COLS: usize, const ROWS: usize> Canvas<COLS, ROWS> {
+    pub const WIDTH: u16 = COLS as u16 * 5;
+    pub const HEIGHT: u16 = ROWS as u16 * 8;
+
+    pub fn new(row:u8, col:u8) -> Self {
+        Self {
+            row,
+            col,
+            tiles: [[EMPTY; COLS]; ROWS],
+            loaded: [None; SLOTS],
+            shown: [[b' '; COLS]; ROWS],
+            synced: false,
+        }
+    }
+
+    /// Clears all pixels.
+    pub fn clear(&mut self) -> &mut Self {
+        self.tiles = [[EMPTY; COLS]; ROWS];
+        self
+    }
+
+    /// Makes the next flush upload every tile and print every cell, e.g. after the display was cleared.
+    pub fn invalidate(&mut self) -> &mut Self {
+        self.synced = false;
+        self
+    }
+
+    /// Pixel at `x`/`y`, `false` outside of the canvas.
+    pub fn pixel(&self, x:u16, y:u16) -> bool {
+        x < Self::WIDTH && y < Self::HEIGHT
+            && self.tiles[(y / 8) as usize][(x / 5) as usize][(y % 8) as usize] & (0x10 >> (x % 5)) != 0
+    }
+
+    /// Sets or clears the pixel at `x`/`y`, drawing outside of the canvas is clipped.
+    pub fn set_pixel(&mut self, x:u16, y:u16, on:bool) -> &mut Self {
+        if x < Self::WIDTH && y < Self::HEIGHT {
+            let row = &mut self.tiles[(y / 8) as usize][(x / 5) as usize][(y % 8) as usize];
+            let bit = 0x10 >> (x % 5);
+            if on { *row |= bit } else { *row &= !bit }
+        }
+        self
+    }
+
+    // Gives every distinct non-empty tile a slot, keeping slots which already hold one of them.
+    // Returns the slot contents and the code of every cell, `None` with more than 8 distinct tiles.
+    fn assign(&self) -> Option<(Slots, [[u8; COLS]; ROWS])> {
+        let mut unique = [EMPTY; SLOTS];
+        let mut count = 0;
+        for &tile in self.tiles.iter().flatten() {
+            if tile != EMPTY && !unique[..count].contains(&tile) {
+                if count == SLOTS {
+                    return None;
+                }
+                unique[count] = tile;
+                count += 1;
+            }
+        }
+
+        let mut slots = self.loaded;
+        let mut used = [false; SLOTS];
+        for tile in &unique[..count] {
+            if let Some(slot) = slots.iter().position(|&loaded| loaded == Some(*tile)) {
+                used[slot] = true;
+            }
+        }
+        for tile in &unique[..count] {
+            if !slots.iter().zip(used).any(|(&loaded, used)| used && loaded == Some(*tile)) {
+                let slot = used.iter().position(|used| !used)?;
+                slots[slot] = Some(*tile);
+                used[slot] = true;
+            }
+        }
+
+        let mut codes = [[b' '; COLS]; ROWS];
+        for (tiles, codes) in self.tiles.iter().zip(codes.iter_mut()) {
+            for (&tile, code) in tiles.iter().zip(codes.iter_mut()) {
+                if tile != EMPTY {
+                    let slot = slots.iter().zip(used).position(|(&loaded, used)| used && loaded == Some(tile))?;
+                    *code = CustomFont5x8::ALL[slot] as u8;
+                }
+            }
+        }
+        Some((slots, codes))
+    }
+
+    fn changed(&self, codes:&[[u8; COLS]; ROWS], row:usize, col:usize) -> bool {
+        !self.synced || codes[row][col] != self.shown[row][col]
+    }
+
+    /// Uploads the tiles and prints the cells which changed since the last flush.
+    /// Fails with `CgramFull` before sending anything if more than 8 distinct tiles are drawn.
+    pub fn flush<INTERFACE>(&mut self, dp:&mut Hd44780<INTERFACE, DisplayTypeFont5x8>) -> Result<(), Hd44780Error<INTERFACE::Error>>
+    where
+        INTERFACE: InterfaceTrait,
+    {
+        let (slots, codes) = self.assign().ok_or(Hd44780Error::CgramFull)?;
+        for (slot, (&tile, &loaded)) in slots.iter().zip(self.loaded.iter()).enumerate() {
+            if let Some(glyph) = tile && (!self.synced || tile != loaded) {
+                dp.create_char(CustomFont5x8::ALL[slot], glyph)?;
+            }
+        }
+        self.loaded = slots;
+
+        for row in 0..ROWS {
+            flush_row(dp, self.row + row as u8, self.col, &codes[row], |col| self.changed(&codes, row, col))?;
+        }
+        self.shown = codes;
+        self.synced = true;
+        Ok(())
+    }
+
+    /// Async counterpart of [`Canvas::flush`].
+    #[cfg(feature="async")]
+    pub async fn flush_async<INTERFACE>(&mut self, dp:&mut Hd44780Async<INTERFACE, DisplayTypeFont5x8>) -> Result<(), Hd44780Error<INTERFACE::Error>>
+    where
+        INTERFACE: AsyncInterfaceTrait,
+    {
+        let (slots, codes) = self.assign().ok_or(Hd44780Error::CgramFull)?;
+        for (slot, (&tile, &loaded)) in slots.iter().zip(self.loaded.iter()).enumerate() {
+            if let Some(glyph) = tile && (!self.synced || tile != loaded) {
+                dp.create_char(CustomFont5x8::ALL[slot], glyph).await?;
+            }
+        }
+        self.loaded = slots;
+
+        for row in 0..ROWS {
+            flush_row_async(dp, self.row + row as u8, self.col, &codes[row], |col| self.changed(&codes, row, col)).await?;
+        }
+        self.shown = codes;
+        self.synced = true;
+        Ok(())
+    }
+}
+
+
+#[cfg(feature="embedded-graphics")]
+impl<const COLS: usize, const ROWS: usize> embedded_graphics_core::geometry::OriginDimensions for Canvas<COLS, ROWS> {
+    fn size(&self) -> embedded_graphics_core::geometry::Size {
+        embedded_graphics_core::geometry::Size::new(Self::WIDTH as u32, Self::HEIGHT as u32)
+    }
+}
+
+#[cfg(feature="embedded-graphics")]
+impl<const COLS: usize, const ROWS: usize> embedded_graphics_core::draw_target::DrawTarget for Canvas<COLS, ROWS> {
+    type Color = embedded_graphics_core::pixelcolor::BinaryColor;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = embedded_graphics_core::Pixel<Self::Color>>,
+    {
+        for embedded_graphics_core::Pixel(point, color) in pixels {
+            if let (Ok(x), Ok(y)) = (u16::try_from(point.x), u16::try_from(point.y)) {
+                self.set_pixel(x, y, color.is_on());
+            }
+        }
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        let row = if color.is_on() { 0x1f } else { 0 };
+        self.tiles = [[[row; 8]; COLS]; ROWS];
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // sets pixel `pixel` of tile `tile` in the top row, counting left to right and top to bottom
+    fn mark<const COLS: usize, const ROWS: usize>(canvas:&mut Canvas<COLS, ROWS>, tile:u16, pixel:u16) {
+        canvas.set_pixel(tile * 5 + pixel % 5, pixel / 5, true);
+    }
+
+    #[test]
+    fn equal_tiles_share_a_slot() {
+        let mut canvas = Canvas::<4, 1>::new(0, 0);
+        mark(&mut canvas, 0, 0);
+        mark(&mut canvas, 2, 0);
+        mark(&mut canvas, 3, 1);
+        let (slots, codes) = canvas.assign().unwrap();
+        assert_eq!(codes, [[0x08, b' ', 0x08, 0x09]]);
+        assert_eq!(slots.iter().filter(|slot| slot.is_some()).count(), 2);
+    }
+
+    #[test]
+    fn loaded_tiles_keep_their_slots() {
+        let mut canvas = Canvas::<2, 1>::new(0, 0);
+        mark(&mut canvas, 0, 0);
+        mark(&mut canvas, 1, 1);
+        let (slots, _) = canvas.assign().unwrap();
+        canvas.loaded = slots;
+
+        canvas.clear();
+        mark(&mut canvas, 0, 2);
+        mark(&mut canvas, 1, 1);
+        let (_, codes) = canvas.assign().unwrap();
+        assert_eq!(codes, [[0x08, 0x09]]);
+    }
+
+    #[test]
+    fn nine_distinct_tiles_fail() {
+        let mut canvas = Canvas::<9, 1>::new(0, 0);
+        for tile in 0..8 {
+            mark(&mut canvas, tile, tile);
+        }
+        assert!(canvas.assign().is_some());
+        mark(&mut canvas, 8, 8);
+        assert!(canvas.assign().is_none());
+
+        // a copy of the first tile fits again
+        canvas.set_pixel(8 * 5 + 3, 1, false);
+        mark(&mut canvas, 8, 0);
+        let (_, codes) = canvas.assign().unwrap();
+        assert_eq!(codes[0][8], codes[0][0]);
+    }
+}
@@ -0,0 +1,112 @@
+// Glyphs for characters which are missing from the character ROMs, used by `print_string`
+// with `rom_fallback` enabled. They cover German, Polish and Russian text.
+
+use crate::glyph;
+use super::{Glyph, Glyph5x8};
+
+
+/// Character of the ROMs which looks the same as `c`, e.g. Latin `A` for Cyrillic `А`.
+/// Printing it needs no custom character.
+pub const fn lookalike(c: char) -> Option<char> {
+    match c {
+        'А' => Some('A'), 'В' => Some('B'), 'Е' => Some('E'), 'К' => Some('K'),
+        'М' => Some('M'), 'Н' => Some('H'), 'О' => Some('O'), 'Р' => Some('P'),
+        'С' => Some('C'), 'Т' => Some('T'), 'Х' => Some('X'), 'Ё' => Some('Ë'),
+        'а' => Some('a'), 'е' => Some('e'), 'о' => Some('o'), 'р' => Some('p'),
+        'с' => Some('c'), 'у' => Some('y'), 'х' => Some('x'), 'ё' => Some('ë'),
+        // degree, micro and ohm sign
+        '\u{00b0}' => Some('\u{309c}'), '\u{00b5}' => Some('\u{03bc}'), '\u{2126}' => Some('\u{03a9}'),
+        _ => None,
+    }
+}
+
+/// Glyph drawing `c` in a custom character, `None` if the library has none.
+pub const fn glyph(c: char) -> Option<Glyph5x8> {
+    let rows = match c {
+        // German
+        'Ä' => glyph!("#...#", ".###.", "#...#", "#...#", "#####", "#...#", "#...#", "....."),
+        'Ö' => glyph!("#...#", ".###.", "#...#", "#...#", "#...#", "#...#", ".###.", "....."),
+        'Ü' => glyph!("#...#", ".....", "#...#", "#...#", "#...#", "#...#", ".###.", "....."),
+        'ä' => glyph!(".#.#.", ".....", ".###.", "....#", ".####", "#...#", ".####", "....."),
+        'ö' => glyph!(".#.#.", ".....", ".###.", "#...#", "#...#", "#...#", ".###.", "....."),
+        'ü' => glyph!(".#.#.", ".....", "#...#", "#...#", "#...#", "#..##", ".##.#", "....."),
+        'ß' => glyph!(".##..", "#..#.", "#..#.", "#.##.", "#...#", "#...#", "#.##.", "....."),
+
+        // Polish, the ogonek hangs into the cursor row
+        'Ą' => glyph!(".###.", "#...#", "#...#", "#####", "#...#", "#...#", "#...#", "...##"),
+        'Ć' => glyph!("...#.", ".###.", "#...#", "#....", "#....", "#...#", ".###.", "....."),
+        'Ę' => glyph!("#####", "#....", "#....", "####.", "#....", "#....", "#####", "...##"),
+        'Ł' => glyph!(".#...", ".#...", ".#.#.", ".##..", "##...", ".#...", ".####", "....."),
+        'Ń' => glyph!("...#.", "#...#", "##..#", "#.#.#", "#..##", "#...#", "#...#", "....."),
+        'Ó' => glyph!("...#.", ".###.", "#...#", "#...#", "#...#", "#...#", ".###.", "....."),
+        'Ś' => glyph!("...#.", ".####", "#....", ".###.", "....#", "....#", "####.", "....."),
+        'Ź' => glyph!("...#.", "#####", "...#.", "..#..", ".#...", "#....", "#####", "....."),
+        'Ż' => glyph!("..#..", "#####", "...#.", "..#..", ".#...", "#....", "#####", "....."),
+        'ą' => glyph!(".....", ".....", ".###.", "....#", ".####", "#...#", ".####", "...##"),
+        'ć' => glyph!("...#.", "..#..", ".###.", "#....", "#....", "#...#", ".###.", "....."),
+        'ę' => glyph!(".....", ".....", ".###.", "#...#", "#####", "#....", ".###.", "...##"),
+        'ł' => glyph!(".##..", "..#..", "..#.#", "..##.", ".##..", "#.#..", ".###.", "....."),
+        'ń' => glyph!("...#.", "..#..", "#.##.", "##..#", "#...#", "#...#", "#...#", "....."),
+        'ó' => glyph!("...#.", "..#..", ".###.", "#...#", "#...#", "#...#", ".###.", "....."),
+        'ś' => glyph!("...#.", "..#..", ".###.", "#....", ".###.", "....#", "####.", "....."),
+        'ź' => glyph!("...#.", "..#..", "#####", "...#.", "..#..", ".#...", "#####", "....."),
+        'ż' => glyph!("..#..", ".....", "#####", "...#.", "..#..", ".#...", "#####", "....."),
+
+        // Cyrillic letters without a Latin lookalike
+        'Б' => glyph!("#####", "#....", "#....", "####.", "#...#", "#...#", "####.", "....."),
+        'Г' => glyph!("#####", "#....", "#....", "#....", "#....", "#....", "#....", "....."),
+        'Д' => glyph!("..##.", ".#.#.", ".#.#.", ".#.#.", ".#.#.", "#####", "#...#", "....."),
+        'Ё' => glyph!(".#.#.", "#####", "#....", "####.", "#....", "#....", "#####", "....."),
+        'Ж' => glyph!("#.#.#", "#.#.#", "#.#.#", ".###.", "#.#.#", "#.#.#", "#.#.#", "....."),
+        'З' => glyph!("####.", "....#", "....#", ".###.", "....#", "....#", "####.", "....."),
+        'И' => glyph!("#...#", "#...#", "#..##", "#.#.#", "##..#", "#...#", "#...#", "....."),
+        'Й' => glyph!(".#.#.", "..#..", "#...#", "#..##", "#.#.#", "##..#", "#...#", "....."),
+        'Л' => glyph!("..###", ".#..#", ".#..#", ".#..#", ".#..#", ".#..#", "#...#", "....."),
+        'П' => glyph!("#####", "#...#", "#...#", "#...#", "#...#", "#...#", "#...#", "....."),
+        'У' => glyph!("#...#", "#...#", "#...#", ".####", "....#", "....#", ".###.", "....."),
+        'Ф' => glyph!("..#..", ".###.", "#.#.#", "#.#.#", "#.#.#", ".###.", "..#..", "....."),
+        'Ц' => glyph!("#..#.", "#..#.", "#..#.", "#..#.", "#..#.", "#####", "....#", "....."),
+        'Ч' => glyph!("#...#", "#...#", "#...#", ".####", "....#", "....#", "....#", "....."),
+        'Ш' => glyph!("#.#.#", "#.#.#", "#.#.#", "#.#.#", "#.#.#", "#.#.#", "#####", "....."),
+        'Щ' => glyph!("#.#.#", "#.#.#", "#.#.#", "#.#.#", "#.#.#", "#####", "....#", "....."),
+        'Ъ' => glyph!("##...", ".#...", ".#...", ".###.", ".#..#", ".#..#", ".###.", "....."),
+        'Ы' => glyph!("#...#", "#...#", "#...#", "##..#", "#.#.#", "#.#.#", "##..#", "....."),
+        'Ь' => glyph!("#....", "#....", "#....", "####.", "#...#", "#...#", "####.", "....."),
+        'Э' => glyph!(".###.", "#...#", "....#", "..###", "....#", "#...#", ".###.", "....."),
+        'Ю' => glyph!("#..#.", "#.#.#", "#.#.#", "###.#", "#.#.#", "#.#.#", "#..#.", "....."),
+        'Я' => glyph!(".####", "#...#", "#...#", ".####", "..#.#", ".#..#", "#...#", "....."),
+        'б' => glyph!("...#.", ".##..", "#....", "####.", "#...#", "#...#", ".###.", "....."),
+        'в' => glyph!(".....", ".....", "####.", "#...#", "####.", "#...#", "####.", "....."),
+        'г' => glyph!(".....", ".....", "#####", "#....", "#....", "#....", "#....", "....."),
+        'д' => glyph!(".....", ".....", ".##..", ".#.#.", ".#.#.", "#####", "#...#", "....."),
+        'ё' => glyph!(".#.#.", ".....", ".###.", "#...#", "#####", "#....", ".###.", "....."),
+        'ж' => glyph!(".....", ".....", "#.#.#", "#.#.#", ".###.", "#.#.#", "#.#.#", "....."),
+        'з' => glyph!(".....", ".....", "####.", "....#", ".###.", "....#", "####.", "....."),
+        'и' => glyph!(".....", ".....", "#...#", "#..##", "#.#.#", "##..#", "#...#", "....."),
+        'й' => glyph!(".#.#.", "..#..", "#...#", "#..##", "#.#.#", "##..#", "#...#", "....."),
+        'к' => glyph!(".....", ".....", "#..#.", "#.#..", "##...", "#.#..", "#..#.", "....."),
+        'л' => glyph!(".....", ".....", "..###", ".#..#", ".#..#", ".#..#", "#...#", "....."),
+        'м' => glyph!(".....", ".....", "#...#", "##.##", "#.#.#", "#...#", "#...#", "....."),
+        'н' => glyph!(".....", ".....", "#...#", "#...#", "#####", "#...#", "#...#", "....."),
+        'п' => glyph!(".....", ".....", "#####", "#...#", "#...#", "#...#", "#...#", "....."),
+        'т' => glyph!(".....", ".....", "#####", "..#..", "..#..", "..#..", "..#..", "....."),
+        'ф' => glyph!(".....", "..#..", ".###.", "#.#.#", "#.#.#", ".###.", "..#..", "....."),
+        'ц' => glyph!(".....", ".....", "#..#.", "#..#.", "#..#.", "#####", "....#", "....."),
+        'ч' => glyph!(".....", ".....", "#...#", "#...#", ".####", "....#", "....#", "....."),
+        'ш' => glyph!(".....", ".....", "#.#.#", "#.#.#", "#.#.#", "#.#.#", "#####", "....."),
+        'щ' => glyph!(".....", ".....", "#.#.#", "#.#.#", "#.#.#", "#####", "....#", "....."),
+        'ъ' => glyph!(".....", ".....", "##...", ".#...", ".###.", ".#..#", ".###.", "....."),
+        'ы' => glyph!(".....", ".....", "#...#", "#...#", "##..#", "#.#.#", "##..#", "....."),
+        'ь' => glyph!(".....", ".....", "#....", "#....", "####.", "#...#", "####.", "....."),
+        'э' => glyph!(".....", ".....", ".###.", "#...#", "..###", "#...#", ".###.", "....."),
+        'ю' => glyph!(".....", ".....", "#..#.", "#.#.#", "###.#", "#.#.#", "#..#.", "....."),
+        'я' => glyph!(".....", ".....", ".####", "#...#", ".####", ".#..#", "#...#", "....."),
+
+        // ASCII characters A00 replaces
+        '\\' => glyph!(".....", "#....", ".#...", "..#..", "...#.", "....#", ".....", "....."),
+        '~' => glyph!(".....", ".....", ".#...", "#.#.#", "...#.", ".....", ".....", "....."),
+
+        _ => return None,
+    };
+    Some(Glyph::new(rows))
+}
@@ -0,0 +1,277 @@
+
+// Status icons for custom characters, load them with `create_char`, `alloc_char` or as part of a `CharSet`.
+// The generators take a level and clamp it to the range they can draw.
+
+use crate::glyph;
+use super::{Glyph, Glyph5x8, Glyph5x10};
+
+
+pub const BELL: Glyph5x8 = Glyph::new(glyph!(
+    "..#..",
+    ".###.",
+    ".###.",
+    ".###.",
+    "#####",
+    ".....",
+    "..#..",
+    ".....",
+));
+
+pub const LOCK: Glyph5x8 = Glyph::new(glyph!(
+    ".###.",
+    "#...#",
+    "#...#",
+    "#####",
+    "##.##",
+    "##.##",
+    "#####",
+    ".....",
+));
+
+pub const UNLOCK: Glyph5x8 = Glyph::new(glyph!(
+    ".###.",
+    "#...#",
+    "#....",
+    "#####",
+    "##.##",
+    "##.##",
+    "#####",
+    ".....",
+));
+
+/// The ROMs have a degree sign as well (A00: 0xdf, A02: 0xb0), this one sits higher like `'°'`.
+pub const DEGREE: Glyph5x8 = Glyph::new(glyph!(
+    ".##..",
+    "#..#.",
+    "#..#.",
+    ".##..",
+    ".....",
+    ".....",
+    ".....",
+    ".....",
+));
+
+pub const HEART: Glyph5x8 = Glyph::new(glyph!(
+    ".....",
+    ".#.#.",
+    "#####",
+    "#####",
+    ".###.",
+    "..#..",
+    ".....",
+    ".....",
+));
+
+pub const HEART_OUTLINE: Glyph5x8 = Glyph::new(glyph!(
+    ".....",
+    ".#.#.",
+    "#.#.#",
+    "#...#",
+    ".#.#.",
+    "..#..",
+    ".....",
+    ".....",
+));
+
+pub const ARROW_UP: Glyph5x8 = Glyph::new(glyph!(
+    "..#..",
+    ".###.",
+    "#.#.#",
+    "..#..",
+    "..#..",
+    "..#..",
+    "..#..",
+    ".....",
+));
+
+pub const ARROW_DOWN: Glyph5x8 = Glyph::new(glyph!(
+    "..#..",
+    "..#..",
+    "..#..",
+    "..#..",
+    "#.#.#",
+    ".###.",
+    "..#..",
+    ".....",
+));
+
+pub const ARROW_LEFT: Glyph5x8 = Glyph::new(glyph!(
+    ".....",
+    "..#..",
+    ".#...",
+    "#####",
+    ".#...",
+    "..#..",
+    ".....",
+    ".....",
+));
+
+pub const ARROW_RIGHT: Glyph5x8 = ARROW_LEFT.mirror_horizontal();
+
+pub const CHECK: Glyph5x8 = Glyph::new(glyph!(
+    ".....",
+    "....#",
+    "...##",
+    "#.##.",
+    "###..",
+    ".#...",
+    ".....",
+    ".....",
+));
+
+pub const CROSS: Glyph5x8 = Glyph::new(glyph!(
+    ".....",
+    "#...#",
+    ".#.#.",
+    "..#..",
+    ".#.#.",
+    "#...#",
+    ".....",
+    ".....",
+));
+
+pub const PLAY: Glyph5x8 = Glyph::new(glyph!(
+    "#....",
+    "##...",
+    "###..",
+    "####.",
+    "###..",
+    "##...",
+    "#....",
+    ".....",
+));
+
+pub const PAUSE: Glyph5x8 = Glyph::new(glyph!(
+    "##.##",
+    "##.##",
+    "##.##",
+    "##.##",
+    "##.##",
+    "##.##",
+    "##.##",
+    ".....",
+));
+
+pub const STOP: Glyph5x8 = Glyph::new(glyph!(
+    ".....",
+    "#####",
+    "#####",
+    "#####",
+    "#####",
+    "#####",
+    ".....",
+    ".....",
+));
+
+
+// Battery outline with the cap on top, `level` of the inner rows are filled from the bottom.
+const fn battery_glyph<const ROWS: usize>(level: u8) -> Glyph<ROWS> {
+    let mut rows = [0b1_0001; ROWS];
+    rows[0] = 0b0_1110;
+    rows[1] = 0b1_1111;
+    rows[ROWS - 1] = 0b1_1111;
+
+    let inner = ROWS - 3;
+    let level = if level as usize > inner { inner } else { level as usize };
+    let mut i = 0;
+    while i < level {
+        rows[ROWS - 2 - i] = 0b1_1111;
+        i += 1;
+    }
+    Glyph::new(rows)
+}
+
+/// Battery with `level` from 0 (empty) to 5 (full).
+pub const fn battery(level: u8) -> Glyph5x8 {
+    battery_glyph(level)
+}
+
+/// Battery with `level` from 0 (empty) to 7 (full).
+pub const fn battery_5x10(level: u8) -> Glyph5x10 {
+    battery_glyph(level)
+}
+
+// Five bars rising to the right, bars above `bars` only show their base pixel.
+const fn signal_glyph<const ROWS: usize>(bars: u8, heights: [usize; 5]) -> Glyph<ROWS> {
+    let mut glyph = Glyph::EMPTY;
+    let mut x = 0;
+    while x < 5 {
+        let height = if x < bars as usize { heights[x] } else { 1 };
+        let mut y = 0;
+        while y < height {
+            glyph.set_pixel(x as u8, (ROWS - 1 - y) as u8, true);
+            y += 1;
+        }
+        x += 1;
+    }
+    glyph
+}
+
+/// Signal strength with `bars` from 0 to 5.
+pub const fn signal(bars: u8) -> Glyph5x8 {
+    signal_glyph(bars, [2, 3, 5, 6, 8])
+}
+
+/// Signal strength with `bars` from 0 to 5.
+pub const fn signal_5x10(bars: u8) -> Glyph5x10 {
+    signal_glyph(bars, [2, 4, 6, 8, 10])
+}
+
+/// Wi-Fi with `arcs` from 0 (only the dot) to 2 above the dot.
+pub const fn wifi(arcs: u8) -> Glyph5x8 {
+    let mut rows = glyph!(
+        ".###.",
+        "#...#",
+        "..#..",
+        ".#.#.",
+        ".....",
+        "..#..",
+        ".....",
+        ".....",
+    );
+    if arcs < 2 {
+        rows[0] = 0;
+        rows[1] = 0;
+    }
+    if arcs < 1 {
+        rows[2] = 0;
+        rows[3] = 0;
+    }
+    Glyph::new(rows)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn battery_fills_from_the_bottom() {
+        assert_eq!(battery(0).rows(), [0x0e, 0x1f, 0x11, 0x11, 0x11, 0x11, 0x11, 0x1f]);
+        assert_eq!(battery(2).rows(), [0x0e, 0x1f, 0x11, 0x11, 0x11, 0x1f, 0x1f, 0x1f]);
+        assert_eq!(battery(5).rows(), [0x0e, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f]);
+        assert_eq!(battery(99), battery(5));
+        assert_eq!(battery_5x10(0).rows(), [0x0e, 0x1f, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x1f]);
+        assert_eq!(battery_5x10(7).rows(), [0x0e, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f]);
+        assert_eq!(battery_5x10(8), battery_5x10(7));
+    }
+
+    #[test]
+    fn signal_shows_the_base_of_missing_bars() {
+        assert_eq!(signal(0).rows(), [0, 0, 0, 0, 0, 0, 0, 0x1f]);
+        assert_eq!(signal(2).rows(), [0, 0, 0, 0, 0, 0x08, 0x18, 0x1f]);
+        assert_eq!(signal(5).rows(), [0x01, 0x01, 0x03, 0x07, 0x07, 0x0f, 0x1f, 0x1f]);
+        assert_eq!(signal(9), signal(5));
+        assert_eq!(signal_5x10(0).rows(), [0, 0, 0, 0, 0, 0, 0, 0, 0, 0x1f]);
+        assert_eq!(signal_5x10(5).rows(), [0x01, 0x01, 0x03, 0x03, 0x07, 0x07, 0x0f, 0x0f, 0x1f, 0x1f]);
+        assert_eq!(signal_5x10(6), signal_5x10(5));
+    }
+
+    #[test]
+    fn wifi_adds_arcs_above_the_dot() {
+        assert_eq!(wifi(0).rows(), [0, 0, 0, 0, 0, 0x04, 0, 0]);
+        assert_eq!(wifi(1).rows(), [0, 0, 0x04, 0x0a, 0, 0x04, 0, 0]);
+        assert_eq!(wifi(2).rows(), [0x0e, 0x11, 0x04, 0x0a, 0, 0x04, 0, 0]);
+        assert_eq!(wifi(3), wifi(2));
+    }
+}
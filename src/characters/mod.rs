@@ -0,0 +1,1586 @@
+pub mod icons;
+pub mod fallback;
+#[cfg(feature="rom-glyphs")]
+mod rom;
+
+use core::fmt::{self, Write};
+
+use crate::FnsetFont;
+
+
+
+/// Non ASCII chars for ROM Coce: A00 (japanese)
+/// This ROM include ASCII chars: ' ' (space) <-> '~'
+///                               0x20        <-> 0x7d
+/// Except the ASCI '\' char (0x5c) represents the Yen Sign
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NonASCIIA00 {
+    // ASCII Realm
+    // 0x00 <-> 0x0f  CG RAM
+    // 0x10 <-> 0x1f  All White
+    // 0x20 <-> 0x5b  Corresponde with ASCI
+    YenSign             = 0x5c, // U+00A5 , ASCII: '\'
+    // 0x5d <-> 0x7d  Corresponde with ASCI
+    RightwardsArrow     = 0x7e, // U+2192
+    LeftwardsArrow      = 0x7f, // U+2190
+
+    // None ASCII Realm
+    // 0x80 <-> 0x9f  All White
+    // 0xa0           All White
+    
+    IdeographicFullStop = 0xa1, // U+3002
+    LeftCornerBracket   = 0xa2, // U+300c
+    RightCornerBracket  = 0xa3, // U+300d
+    IdeographicComma    = 0xa4, // U+3001
+    KatakanaMiddleDot   = 0xa5, // U+30fb
+    KatakanaWo          = 0xa6, // U+30f2
+    KatakanaASmall      = 0xa7, // U+30a1
+    KatakanaISmall      = 0xa8, // U+30a3
+    KatakanaUSmall      = 0xa9, // U+30a5
+    KatakanaESmall      = 0xaa, // U+30a7
+    KatakanaOSmall      = 0xab, // U+30a9
+    KatakanaYaSmall     = 0xac, // U+30e3
+    KatakanaYuSmall     = 0xad, // U+30e5
+    KatakanaYoSmall     = 0xae, // U+30e7
+    KatakanaTuSmall     = 0xaf, // U+30c3
+    
+    KatakanaHiraganaProlongedSoundMark = 0xb0, // U+30fc
+    KatakanaA           = 0xb1, // U+30a2
+    KatakanaI           = 0xb2, // U+30a4
+    KatakanaU           = 0xb3, // U+30a6
+    KatakanaE           = 0xb4, // U+30a8
+    KatakanaO           = 0xb5, // U+30aa
+    KatakanaKa          = 0xb6, // U+30ab
+    KatakanaKi          = 0xb7, // U+30ad
+    KatakanaKu          = 0xb8, // U+30af
+    KatakanaKe          = 0xb9, // U+30b1
+    KatakanaKo          = 0xba, // U+30b3
+    KatakanaSa          = 0xbb, // U+30b5
+    KatakanaSi          = 0xbc, // U+30b7
+    KatakanaSu          = 0xbd, // U+30b9
+    KatakanaSe          = 0xbe, // U+30bb
+    KatakanaSo          = 0xbf, // U+30bd
+
+    KatakanaTa          = 0xc0, // U+30bf
+    KatakanaTi          = 0xc1, // U+30c1
+    KatakanaTu          = 0xc2, // U+30c4
+    KatakanaTe          = 0xc3, // U+30c6
+    KatakanaTo          = 0xc4, // U+30c8
+    KatakanaNa          = 0xc5, // U+30ca
+    KatakanaNi          = 0xc6, // U+30cb
+    KatakanaNu          = 0xc7, // U+30cc
+    KatakanaNe          = 0xc8, // U+30cd
+    KatakanaNo          = 0xc9, // U+30ce
+    KatakanaHa          = 0xca, // U+30cf
+    KatakanaHi          = 0xcb, // U+30d2
+    KatakanaHu          = 0xcc, // U+30d5
+    KatakanaHe          = 0xcd, // U+30d8
+    KatakanaHo          = 0xce, // U+30db
+    KatakanaMa          = 0xcf, // U+30de
+
+    KatakanaMi          = 0xd0, // U+30df
+    KatakanaMu          = 0xd1, // U+30e0
+    KatakanaMe          = 0xd2, // U+30e1
+    KatakanaMo          = 0xd3, // U+30e2
+    KatakanaYa          = 0xd4, // U+30e4
+    KatakanaYu          = 0xd5, // U+30e6
+    KatakanaYo          = 0xd6, // U+30e8
+    KatakanaRa          = 0xd7, // U+30e9
+    KatakanaRi          = 0xd8, // U+30ea
+    KatakanaRu          = 0xd9, // U+30eb
+    KatakanaRe          = 0xda, // U+30ec
+    KatakanaRo          = 0xdb, // U+30ed
+    KatakanaWa          = 0xdc, // U+30ef
+    KatakanaN           = 0xdd, // U+30f3
+    KatakanaHiraganaVoicedSoundMark = 0xde, // U+309b
+    KatakanaHiraganaSemiVoicedSoundMark = 0xdf, // U+309c
+
+    GreekSmallAlpha     = 0xe0, // U+03b1
+    LatinSmallUmlautA   = 0xe1, // U+00e4
+    GreekSmallBeta      = 0xe2, // U+03b2
+    GreekSmallEpsilon   = 0xe3, // U+03b5
+    GreekSmallMu        = 0xe4, // U+03bc
+    GreekSmallSigma     = 0xe5, // U+03c3
+    GreekSmallRho       = 0xe6, // U+03c1
+    LatinSmallGDecender = 0xe7, // ---
+    SquareRoot          = 0xe8, // U+221a
+    SuperscriptMinusOne = 0xe9, // ---
+    LatinSmallJDecender = 0xea, // ---
+    SuperscriptX        = 0xeb, // U+02e3
+    CentSign            = 0xec, // U+00a2
+    PoundSign           = 0xed, // U+00a3
+    LatinSmallNLineAbove = 0xee, // ---
+    LatinSmallUmlautO   = 0xef, // U+00f6
+
+    LatinSmallPDecender = 0xf0, // ---
+    LatinSmallQDecender = 0xf1, // ---
+    GreekSmallTheta     = 0xf2, // U+03b8
+    Infinity            = 0xf3, // U+221E
+    GreekCapitalOmega   = 0xf4, // U+03a9
+    LatinSmallUmlautU   = 0xf5, // U+00fc
+    GreekCapitalSigma   = 0xf6, // U+03a3
+    GreekSmallPi        = 0xf7, // U+03c0
+    LatinSmallXBar      = 0xf8, // Average
+    LatinSmallYDecender = 0xf9, // ---
+    CjkThousand         = 0xfa, // U+5343
+    CjkTenThousand      = 0xfb, // U+4e07
+    CjkYen              = 0xfc, // U+5186
+    DivisionSign        = 0xfd, // U+00f7
+    AllWhite            = 0xfe,
+    AllBlack            = 0xff, // U+2588
+}
+
+
+impl NonASCIIA00 {
+    #[deprecated(note = "0xa4 is the ideographic comma, use `IdeographicComma`")]
+    #[allow(non_upper_case_globals)]
+    pub const KatakanaIterationMark: Self = Self::IdeographicComma;
+
+    /// Unicode equivalent of the ROM character, if there is one.
+    pub const fn to_char(self) -> Option<char> {
+        match self {
+            Self::YenSign                             => Some('\u{00a5}'),
+            Self::RightwardsArrow                     => Some('\u{2192}'),
+            Self::LeftwardsArrow                      => Some('\u{2190}'),
+            Self::IdeographicFullStop                 => Some('\u{3002}'),
+            Self::LeftCornerBracket                   => Some('\u{300c}'),
+            Self::RightCornerBracket                  => Some('\u{300d}'),
+            Self::IdeographicComma                    => Some('\u{3001}'),
+            Self::KatakanaMiddleDot                   => Some('\u{30fb}'),
+            Self::KatakanaWo                          => Some('\u{30f2}'),
+            Self::KatakanaASmall                      => Some('\u{30a1}'),
+            Self::KatakanaISmall                      => Some('\u{30a3}'),
+            Self::KatakanaUSmall                      => Some('\u{30a5}'),
+            Self::KatakanaESmall                      => Some('\u{30a7}'),
+            Self::KatakanaOSmall                      => Some('\u{30a9}'),
+            Self::KatakanaYaSmall                     => Some('\u{30e3}'),
+            Self::KatakanaYuSmall                     => Some('\u{30e5}'),
+            Self::KatakanaYoSmall                     => Some('\u{30e7}'),
+            Self::KatakanaTuSmall                     => Some('\u{30c3}'),
+            Self::KatakanaHiraganaProlongedSoundMark  => Some('\u{30fc}'),
+            Self::KatakanaA                           => Some('\u{30a2}'),
+            Self::KatakanaI                           => Some('\u{30a4}'),
+            Self::KatakanaU                           => Some('\u{30a6}'),
+            Self::KatakanaE                           => Some('\u{30a8}'),
+            Self::KatakanaO                           => Some('\u{30aa}'),
+            Self::KatakanaKa                          => Some('\u{30ab}'),
+            Self::KatakanaKi                          => Some('\u{30ad}'),
+            Self::KatakanaKu                          => Some('\u{30af}'),
+            Self::KatakanaKe                          => Some('\u{30b1}'),
+            Self::KatakanaKo                          => Some('\u{30b3}'),
+            Self::KatakanaSa                          => Some('\u{30b5}'),
+            Self::KatakanaSi                          => Some('\u{30b7}'),
+            Self::KatakanaSu                          => Some('\u{30b9}'),
+            Self::KatakanaSe                          => Some('\u{30bb}'),
+            Self::KatakanaSo                          => Some('\u{30bd}'),
+            Self::KatakanaTa                          => Some('\u{30bf}'),
+            Self::KatakanaTi                          => Some('\u{30c1}'),
+            Self::KatakanaTu                          => Some('\u{30c4}'),
+            Self::KatakanaTe                          => Some('\u{30c6}'),
+            Self::KatakanaTo                          => Some('\u{30c8}'),
+            Self::KatakanaNa                          => Some('\u{30ca}'),
+            Self::KatakanaNi                          => Some('\u{30cb}'),
+            Self::KatakanaNu                          => Some('\u{30cc}'),
+            Self::KatakanaNe                          => Some('\u{30cd}'),
+            Self::KatakanaNo                          => Some('\u{30ce}'),
+            Self::KatakanaHa                          => Some('\u{30cf}'),
+            Self::KatakanaHi                          => Some('\u{30d2}'),
+            Self::KatakanaHu                          => Some('\u{30d5}'),
+            Self::KatakanaHe                          => Some('\u{30d8}'),
+            Self::KatakanaHo                          => Some('\u{30db}'),
+            Self::KatakanaMa                          => Some('\u{30de}'),
+            Self::KatakanaMi                          => Some('\u{30df}'),
+            Self::KatakanaMu                          => Some('\u{30e0}'),
+            Self::KatakanaMe                          => Some('\u{30e1}'),
+            Self::KatakanaMo                          => Some('\u{30e2}'),
+            Self::KatakanaYa                          => Some('\u{30e4}'),
+            Self::KatakanaYu                          => Some('\u{30e6}'),
+            Self::KatakanaYo                          => Some('\u{30e8}'),
+            Self::KatakanaRa                          => Some('\u{30e9}'),
+            Self::KatakanaRi                          => Some('\u{30ea}'),
+            Self::KatakanaRu                          => Some('\u{30eb}'),
+            Self::KatakanaRe                          => Some('\u{30ec}'),
+            Self::KatakanaRo                          => Some('\u{30ed}'),
+            Self::KatakanaWa                          => Some('\u{30ef}'),
+            Self::KatakanaN                           => Some('\u{30f3}'),
+            Self::KatakanaHiraganaVoicedSoundMark     => Some('\u{309b}'),
+            Self::KatakanaHiraganaSemiVoicedSoundMark => Some('\u{309c}'),
+            Self::GreekSmallAlpha                     => Some('\u{03b1}'),
+            Self::LatinSmallUmlautA                   => Some('\u{00e4}'),
+            Self::GreekSmallBeta                      => Some('\u{03b2}'),
+            Self::GreekSmallEpsilon                   => Some('\u{03b5}'),
+            Self::GreekSmallMu                        => Some('\u{03bc}'),
+            Self::GreekSmallSigma                     => Some('\u{03c3}'),
+            Self::GreekSmallRho                       => Some('\u{03c1}'),
+            Self::LatinSmallGDecender                 => Some('g'),
+            Self::SquareRoot                          => Some('\u{221a}'),
+            Self::SuperscriptMinusOne                 => None,
+            Self::LatinSmallJDecender                 => Some('j'),
+            Self::SuperscriptX                        => Some('\u{02e3}'),
+            Self::CentSign                            => Some('\u{00a2}'),
+            Self::PoundSign                           => Some('\u{00a3}'),
+            Self::LatinSmallNLineAbove                => None,
+            Self::LatinSmallUmlautO                   => Some('\u{00f6}'),
+            Self::LatinSmallPDecender                 => Some('p'),
+            Self::LatinSmallQDecender                 => Some('q'),
+            Self::GreekSmallTheta                     => Some('\u{03b8}'),
+            Self::Infinity                            => Some('\u{221e}'),
+            Self::GreekCapitalOmega                   => Some('\u{03a9}'),
+            Self::LatinSmallUmlautU                   => Some('\u{00fc}'),
+            Self::GreekCapitalSigma                   => Some('\u{03a3}'),
+            Self::GreekSmallPi                        => Some('\u{03c0}'),
+            Self::LatinSmallXBar                      => None,
+            Self::LatinSmallYDecender                 => Some('y'),
+            Self::CjkThousand                         => Some('\u{5343}'),
+            Self::CjkTenThousand                      => Some('\u{4e07}'),
+            Self::CjkYen                              => Some('\u{5186}'),
+            Self::DivisionSign                        => Some('\u{00f7}'),
+            Self::AllWhite                            => None,
+            Self::AllBlack                            => Some('\u{2588}'),
+        }
+    }
+}
+
+impl TryFrom<u8> for NonASCIIA00 {
+    type Error = u8;
+
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        match code {
+            0x5c => Ok(Self::YenSign),
+            0x7e => Ok(Self::RightwardsArrow),
+            0x7f => Ok(Self::LeftwardsArrow),
+            0xa1 => Ok(Self::IdeographicFullStop),
+            0xa2 => Ok(Self::LeftCornerBracket),
+            0xa3 => Ok(Self::RightCornerBracket),
+            0xa4 => Ok(Self::IdeographicComma),
+            0xa5 => Ok(Self::KatakanaMiddleDot),
+            0xa6 => Ok(Self::KatakanaWo),
+            0xa7 => Ok(Self::KatakanaASmall),
+            0xa8 => Ok(Self::KatakanaISmall),
+            0xa9 => Ok(Self::KatakanaUSmall),
+            0xaa => Ok(Self::KatakanaESmall),
+            0xab => Ok(Self::KatakanaOSmall),
+            0xac => Ok(Self::KatakanaYaSmall),
+            0xad => Ok(Self::KatakanaYuSmall),
+            0xae => Ok(Self::KatakanaYoSmall),
+            0xaf => Ok(Self::KatakanaTuSmall),
+            0xb0 => Ok(Self::KatakanaHiraganaProlongedSoundMark),
+            0xb1 => Ok(Self::KatakanaA),
+            0xb2 => Ok(Self::KatakanaI),
+            0xb3 => Ok(Self::KatakanaU),
+            0xb4 => Ok(Self::KatakanaE),
+            0xb5 => Ok(Self::KatakanaO),
+            0xb6 => Ok(Self::KatakanaKa),
+            0xb7 => Ok(Self::KatakanaKi),
+            0xb8 => Ok(Self::KatakanaKu),
+            0xb9 => Ok(Self::KatakanaKe),
+            0xba => Ok(Self::KatakanaKo),
+            0xbb => Ok(Self::KatakanaSa),
+            0xbc => Ok(Self::KatakanaSi),
+            0xbd => Ok(Self::KatakanaSu),
+            0xbe => Ok(Self::KatakanaSe),
+            0xbf => Ok(Self::KatakanaSo),
+            0xc0 => Ok(Self::KatakanaTa),
+            0xc1 => Ok(Self::KatakanaTi),
+            0xc2 => Ok(Self::KatakanaTu),
+            0xc3 => Ok(Self::KatakanaTe),
+            0xc4 => Ok(Self::KatakanaTo),
+            0xc5 => Ok(Self::KatakanaNa),
+            0xc6 => Ok(Self::KatakanaNi),
+            0xc7 => Ok(Self::KatakanaNu),
+            0xc8 => Ok(Self::KatakanaNe),
+            0xc9 => Ok(Self::KatakanaNo),
+            0xca => Ok(Self::KatakanaHa),
+            0xcb => Ok(Self::KatakanaHi),
+            0xcc => Ok(Self::KatakanaHu),
+            0xcd => Ok(Self::KatakanaHe),
+            0xce => Ok(Self::KatakanaHo),
+            0xcf => Ok(Self::KatakanaMa),
+            0xd0 => Ok(Self::KatakanaMi),
+            0xd1 => Ok(Self::KatakanaMu),
+            0xd2 => Ok(Self::KatakanaMe),
+            0xd3 => Ok(Self::KatakanaMo),
+            0xd4 => Ok(Self::KatakanaYa),
+            0xd5 => Ok(Self::KatakanaYu),
+            0xd6 => Ok(Self::KatakanaYo),
+            0xd7 => Ok(Self::KatakanaRa),
+            0xd8 => Ok(Self::KatakanaRi),
+            0xd9 => Ok(Self::KatakanaRu),
+            0xda => Ok(Self::KatakanaRe),
+            0xdb => Ok(Self::KatakanaRo),
+            0xdc => Ok(Self::KatakanaWa),
+            0xdd => Ok(Self::KatakanaN),
+            0xde => Ok(Self::KatakanaHiraganaVoicedSoundMark),
+            0xdf => Ok(Self::KatakanaHiraganaSemiVoicedSoundMark),
+            0xe0 => Ok(Self::GreekSmallAlpha),
+            0xe1 => Ok(Self::LatinSmallUmlautA),
+            0xe2 => Ok(Self::GreekSmallBeta),
+            0xe3 => Ok(Self::GreekSmallEpsilon),
+            0xe4 => Ok(Self::GreekSmallMu),
+            0xe5 => Ok(Self::GreekSmallSigma),
+            0xe6 => Ok(Self::GreekSmallRho),
+            0xe7 => Ok(Self::LatinSmallGDecender),
+            0xe8 => Ok(Self::SquareRoot),
+            0xe9 => Ok(Self::SuperscriptMinusOne),
+            0xea => Ok(Self::LatinSmallJDecender),
+            0xeb => Ok(Self::SuperscriptX),
+            0xec => Ok(Self::CentSign),
+            0xed => Ok(Self::PoundSign),
+            0xee => Ok(Self::LatinSmallNLineAbove),
+            0xef => Ok(Self::LatinSmallUmlautO),
+            0xf0 => Ok(Self::LatinSmallPDecender),
+            0xf1 => Ok(Self::LatinSmallQDecender),
+            0xf2 => Ok(Self::GreekSmallTheta),
+            0xf3 => Ok(Self::Infinity),
+            0xf4 => Ok(Self::GreekCapitalOmega),
+            0xf5 => Ok(Self::LatinSmallUmlautU),
+            0xf6 => Ok(Self::GreekCapitalSigma),
+            0xf7 => Ok(Self::GreekSmallPi),
+            0xf8 => Ok(Self::LatinSmallXBar),
+            0xf9 => Ok(Self::LatinSmallYDecender),
+            0xfa => Ok(Self::CjkThousand),
+            0xfb => Ok(Self::CjkTenThousand),
+            0xfc => Ok(Self::CjkYen),
+            0xfd => Ok(Self::DivisionSign),
+            0xfe => Ok(Self::AllWhite),
+            0xff => Ok(Self::AllBlack),
+            _ => Err(code),
+        }
+    }
+}
+
+impl TryFrom<char> for NonASCIIA00 {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '\u{00a5}' => Ok(Self::YenSign),
+            '\u{2192}' => Ok(Self::RightwardsArrow),
+            '\u{2190}' => Ok(Self::LeftwardsArrow),
+            '\u{3002}' => Ok(Self::IdeographicFullStop),
+            '\u{300c}' => Ok(Self::LeftCornerBracket),
+            '\u{300d}' => Ok(Self::RightCornerBracket),
+            '\u{3001}' => Ok(Self::IdeographicComma),
+            '\u{30fb}' => Ok(Self::KatakanaMiddleDot),
+            '\u{30f2}' => Ok(Self::KatakanaWo),
+            '\u{30a1}' => Ok(Self::KatakanaASmall),
+            '\u{30a3}' => Ok(Self::KatakanaISmall),
+            '\u{30a5}' => Ok(Self::KatakanaUSmall),
+            '\u{30a7}' => Ok(Self::KatakanaESmall),
+            '\u{30a9}' => Ok(Self::KatakanaOSmall),
+            '\u{30e3}' => Ok(Self::KatakanaYaSmall),
+            '\u{30e5}' => Ok(Self::KatakanaYuSmall),
+            '\u{30e7}' => Ok(Self::KatakanaYoSmall),
+            '\u{30c3}' => Ok(Self::KatakanaTuSmall),
+            '\u{30fc}' => Ok(Self::KatakanaHiraganaProlongedSoundMark),
+            '\u{30a2}' => Ok(Self::KatakanaA),
+            '\u{30a4}' => Ok(Self::KatakanaI),
+            '\u{30a6}' => Ok(Self::KatakanaU),
+            '\u{30a8}' => Ok(Self::KatakanaE),
+            '\u{30aa}' => Ok(Self::KatakanaO),
+            '\u{30ab}' => Ok(Self::KatakanaKa),
+            '\u{30ad}' => Ok(Self::KatakanaKi),
+            '\u{30af}' => Ok(Self::KatakanaKu),
+            '\u{30b1}' => Ok(Self::KatakanaKe),
+            '\u{30b3}' => Ok(Self::KatakanaKo),
+            '\u{30b5}' => Ok(Self::KatakanaSa),
+            '\u{30b7}' => Ok(Self::KatakanaSi),
+            '\u{30b9}' => Ok(Self::KatakanaSu),
+            '\u{30bb}' => Ok(Self::KatakanaSe),
+            '\u{30bd}' => Ok(Self::KatakanaSo),
+            '\u{30bf}' => Ok(Self::KatakanaTa),
+            '\u{30c1}' => Ok(Self::KatakanaTi),
+            '\u{30c4}' => Ok(Self::KatakanaTu),
+            '\u{30c6}' => Ok(Self::KatakanaTe),
+            '\u{30c8}' => Ok(Self::KatakanaTo),
+            '\u{30ca}' => Ok(Self::KatakanaNa),
+            '\u{30cb}' => Ok(Self::KatakanaNi),
+            '\u{30cc}' => Ok(Self::KatakanaNu),
+            '\u{30cd}' => Ok(Self::KatakanaNe),
+            '\u{30ce}' => Ok(Self::KatakanaNo),
+            '\u{30cf}' => Ok(Self::KatakanaHa),
+            '\u{30d2}' => Ok(Self::KatakanaHi),
+            '\u{30d5}' => Ok(Self::KatakanaHu),
+            '\u{30d8}' => Ok(Self::KatakanaHe),
+            '\u{30db}' => Ok(Self::KatakanaHo),
+            '\u{30de}' => Ok(Self::KatakanaMa),
+            '\u{30df}' => Ok(Self::KatakanaMi),
+            '\u{30e0}' => Ok(Self::KatakanaMu),
+            '\u{30e1}' => Ok(Self::KatakanaMe),
+            '\u{30e2}' => Ok(Self::KatakanaMo),
+            '\u{30e4}' => Ok(Self::KatakanaYa),
+            '\u{30e6}' => Ok(Self::KatakanaYu),
+            '\u{30e8}' => Ok(Self::KatakanaYo),
+            '\u{30e9}' => Ok(Self::KatakanaRa),
+            '\u{30ea}' => Ok(Self::KatakanaRi),
+            '\u{30eb}' => Ok(Self::KatakanaRu),
+            '\u{30ec}' => Ok(Self::KatakanaRe),
+            '\u{30ed}' => Ok(Self::KatakanaRo),
+            '\u{30ef}' => Ok(Self::KatakanaWa),
+            '\u{30f3}' => Ok(Self::KatakanaN),
+            '\u{309b}' => Ok(Self::KatakanaHiraganaVoicedSoundMark),
+            '\u{309c}' => Ok(Self::KatakanaHiraganaSemiVoicedSoundMark),
+            '\u{03b1}' => Ok(Self::GreekSmallAlpha),
+            '\u{00e4}' => Ok(Self::LatinSmallUmlautA),
+            '\u{03b2}' => Ok(Self::GreekSmallBeta),
+            '\u{03b5}' => Ok(Self::GreekSmallEpsilon),
+            '\u{03bc}' => Ok(Self::GreekSmallMu),
+            '\u{03c3}' => Ok(Self::GreekSmallSigma),
+            '\u{03c1}' => Ok(Self::GreekSmallRho),
+            '\u{221a}' => Ok(Self::SquareRoot),
+            '\u{02e3}' => Ok(Self::SuperscriptX),
+            '\u{00a2}' => Ok(Self::CentSign),
+            '\u{00a3}' => Ok(Self::PoundSign),
+            '\u{00f6}' => Ok(Self::LatinSmallUmlautO),
+            '\u{03b8}' => Ok(Self::GreekSmallTheta),
+            '\u{221e}' => Ok(Self::Infinity),
+            '\u{03a9}' => Ok(Self::GreekCapitalOmega),
+            '\u{00fc}' => Ok(Self::LatinSmallUmlautU),
+            '\u{03a3}' => Ok(Self::GreekCapitalSigma),
+            '\u{03c0}' => Ok(Self::GreekSmallPi),
+            '\u{5343}' => Ok(Self::CjkThousand),
+            '\u{4e07}' => Ok(Self::CjkTenThousand),
+            '\u{5186}' => Ok(Self::CjkYen),
+            '\u{00f7}' => Ok(Self::DivisionSign),
+            '\u{2588}' => Ok(Self::AllBlack),
+            // descender forms, `Rom::encode` prints the ASCII letters instead
+            'g' => Ok(Self::LatinSmallGDecender),
+            'j' => Ok(Self::LatinSmallJDecender),
+            'p' => Ok(Self::LatinSmallPDecender),
+            'q' => Ok(Self::LatinSmallQDecender),
+            'y' => Ok(Self::LatinSmallYDecender),
+            _ => Err(c),
+        }
+    }
+}
+
+
+
+/// Non ASCII chars for ROM Coce: A02 (european)
+/// This ROM include ASCII chars: ' ' (space) <-> '~'
+///                               0x20        <-> 0x7e
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NonASCIIA02 {
+    // ASCII Realm
+    // 0x00 <-> 0x0f  CG RAM
+    TrianglePointingRight = 0x10, // U+2bc8
+    TrianglePointingLeft  = 0x11, // U+2bc7
+    QuotationMarkLeftDouble  = 0x12, // U+201c
+    QuotationMarkRightDouble = 0x13, // U+201d
+    TriangleDoubleUp      = 0x14, // U+23eb
+    TriangleDoubleDown    = 0x15, // U+23ec
+    BlackLargeCircle      = 0x16, // U+2b24
+    ArrowDownLeft         = 0x17, // U+21b5 (EnterSign)
+    UpwardsArrow          = 0x18, // U+2191
+    DownwardsArrow        = 0x19, // U+2193
+    RightwardsArrow       = 0x1a, // U+2192
+    LeftwardsArrow        = 0x1b, // U+2190
+    LessThanOrEqual       = 0x1c, // U+2264
+    GreaterThanOrEqual    = 0x1d, // U+2265
+    TrianglePointingUp    = 0x1e, // U+2bc5
+    TrianglePointingDown  = 0x1f, // U+2bc6
+    // 0x20 <-> 0x7e  Corresponde with ASCI
+    House  = 0x7f, // U+2303
+
+    // None ASCII Realm
+    CyrillicBe            = 0x80, // U+0411
+    CyrillicDe            = 0x81, // U+0414
+    CyrillicZhe           = 0x82, // U+0416
+    CyrillicZe            = 0x83, // U+0417
+    CyrillicI             = 0x84, // U+0418
+    CirillicShortI        = 0x85, // U+0419
+    CirillicEl            = 0x86, // U+041b
+    CirillicPe            = 0x87, // U+041f
+    CyrillicU             = 0x88, // U+0423
+    CyrillicTse           = 0x89, // U+0426
+    CyrillicChe           = 0x8a, // U+0427
+    CyrillicSha           = 0x8b, // U+0428
+    CyrillicShcha         = 0x8c, // U+0429
+    CyrillicHardSign      = 0x8d, // U+042a
+    CyrillicYeru          = 0x8e, // U+042b
+    CyrillicE             = 0x8f, // U+042d
+    
+
+    GreekSmallAlpha       = 0x90, // U+03b1
+    EighthNote            = 0x91, // U+266a
+    GreekCapitalGamma     = 0x92, // U+0393
+    GreekSmallPi          = 0x93, // U+03c0
+    GreekCapitalSigma     = 0x94, // U+03a3
+    GreekSmallSigma       = 0x95, // U+03c3
+    BeamedDecendingNote   = 0x96, // U+266b
+    GreekSmallTau         = 0x97, // U+03c4
+    BellSymbol            = 0x98, // U+1f514
+    GreekCapitalTheta     = 0x99, // U+0398
+    GreekCapitalOmega     = 0x9a, // U+03a9
+    GreekSmallDelta       = 0x9b, // U+03b4
+    Infinity              = 0x9c, // U+221E
+    BlackHeartSuit        = 0x9d, // U+2665
+    GreekSmallEpsilon     = 0x9e, // U+03b5
+    Intersection          = 0x9f, // U+2229
+
+    HeavyVerticalBars     = 0xa0, // U+2016
+    InvertedExclamationMark = 0xa1, // U+00a1
+    CentSign              = 0xa2, // U+00a2
+    PoundSign             = 0xa3, // U+00a3
+    CurrencySign          = 0xa4, // U+00a4
+    YenSign               = 0xa5, // U+00a5
+    BrokenBar             = 0xa6, // U+00a6
+    SectionSign           = 0xa7, // U+00a7
+    LatinSmallFHook       = 0xa8, // U+0192
+    CopyrightSign         = 0xa9, // U+00a9
+    LatinSmallAUnderbar   = 0xaa, // U+00aa
+    DoubleAngleQuotationMarkLeft = 0xab, // U+00ab
+    CyrillicYu            = 0xac, // U+042e
+    CyrillicYa            = 0xad, // U+042f
+    RegisteredSign        = 0xae, // U+00ae
+    LeftSingleQuotationMark = 0xaf, // U+2018
+
+    DegreeSign            = 0xb0, // U+00b0
+    PlusMinusSign         = 0xb1, // U+00b1
+    SuperscriptTwo        = 0xb2, // U+00b2
+    SuperscriptThree      = 0xb3, // U+00b3
+    PesetaSign            = 0xb4, // U+20a7
+    MicroSign             = 0xb5, // U+00b5
+    PilcrowSign           = 0xb6, // U+00b6
+    MiddleDot             = 0xb7, // U+00b7
+    GreekSmallOmega       = 0xb8, // U+03c9
+    SuperscriptOne        = 0xb9, // U+00b9
+    LatinSmallOUnderbar   = 0xba, // U+00ba
+    DoubleAngleQuotationMarkRight = 0xbb, // U+00bb
+    FractionOneQuarter    = 0xbc, // U+00bc
+    FractionOneHalf       = 0xbd, // U+00bd
+    FractionThreeQuarter  = 0xbe, // U+00be
+    InvertedQuestionMark  = 0xbf, // U+00bf
+
+    LatinCapitalAGrave    = 0xc0, // U+00c0
+    LatinCapitalAAcute    = 0xc1, // U+00c1
+    LatinCapitalACircumflex = 0xc2, // U+00c2
+    LatinCapitalATilde    = 0xc3, // U+00c3
+    LatinCapitalADiaeresis = 0xc4, // U+00c4
+    LatinCapitalARingAbove = 0xc5, // U+00c5
+    LatinCapitalAE         = 0xc6, // U+00c6
+    LatinCapitalCCedilla   = 0xc7, // U+00c7
+    
+    LatinCapitalEGrave    = 0xc8, // U+00c8
+    LatinCapitalEAcute    = 0xc9, // U+00c9
+    LatinCapitalECircumflex = 0xca, // U+00ca
+    LatinCapitalEDiaeresis = 0xcb, // U+00cb
+
+    LatinCapitalIGrave    = 0xcc, // U+00cc
+    LatinCapitalIAcute    = 0xcd, // U+00cd
+    LatinCapitalICircumflex = 0xce, // U+00ce
+    LatinCapitalIDiaeresis = 0xcf, // U+00cf
+    
+    LatinCapitalEth       = 0xd0, // U+00d0
+    LatinCapitalNTilde    = 0xd1, // U+00d1
+
+    LatinCapitalOGrave    = 0xd2, // U+00d2
+    LatinCapitalOAcute    = 0xd3, // U+00d3
+    LatinCapitalOCircumflex = 0xd4, // U+00d4
+    LatinCapitalOTilde    = 0xd5, // U+00d5
+    LatinCapitalODiaeresis = 0xd6, // U+00d6
+
+    MultiplicationSign    = 0xd7, // U+00d7
+
+    LatinCapitalOStroke   = 0xd8, // U+00d8
+
+    LatinCapitalUGrave    = 0xd9, // U+00d9
+    LatinCapitalUAcute    = 0xda, // U+00da
+    LatinCapitalUCircumflex = 0xdb, // U+00db
+    LatinCapitalUDiaeresis = 0xdc, // U+00dc
+
+    LatinCapitalYAcute    = 0xdd, // U+00dd
+    LatinCapitalThorn     = 0xde, // U+00de
+    LatinSmallSharpS      = 0xdf, // U+00df
+
+    LatinSmallAGrave      = 0xe0, // U+00e0
+    LatinSmallAAcute      = 0xe1, // U+00e1
+    LatinSmallACircumflex = 0xe2, // U+00e2
+    LatinSmallATilde      = 0xe3, // U+00e3
+    LatinSmallADiaeresis  = 0xe4, // U+00e4
+    LatinSmallARingAbove  = 0xe5, // U+00e5
+    LatinSmallAE          = 0xe6, // U+00e6
+    LatinSmallCCedilla    = 0xe7, // U+00e7
+
+    LatinSmallEGrave      = 0xe8, // U+00e8
+    LatinSmallEAcute      = 0xe9, // U+00e9
+    LatinSmallECircumflex = 0xea, // U+00ea
+    LatinSmallEDiaeresis  = 0xeb, // U+00eb
+
+    LatinSmallIGrave      = 0xec, // U+00ec
+    LatinSmallIAcute      = 0xed, // U+00ed
+    LatinSmallICircumflex = 0xee, // U+00ee
+    LatinSmallIDiaeresis  = 0xef, // U+00ef
+
+    LatinSmallEth         = 0xf0, // U+00f0
+    LatinSmallNTilde      = 0xf1, // U+00f1
+
+    LatinSmallOGrave      = 0xf2, // U+00f2
+    LatinSmallOAcute      = 0xf3, // U+00f3
+    LatinSmallOCircumflex = 0xf4, // U+00f4
+    LatinSmallOTilde      = 0xf5, // U+00f5
+    LatinSmallODiaeresis  = 0xf6, // U+00f6
+
+    DivisionSign          = 0xf7, // U+00f7
+
+    LatinSmallOStroke     = 0xf8, // U+00f8
+
+    LatinSmallUGrave      = 0xf9, // U+00f9
+    LatinSmallUAcute      = 0xfa, // U+00fa
+    LatinSmallUCircumflex = 0xfb, // U+00fb
+    LatinSmallUDiaeresis  = 0xfc, // U+00fc
+
+    LatinSmallYAcute      = 0xfd, // U+00fd
+    LatinSmallThorn       = 0xfe, // U+00fe
+    LatinSmallYDiaeresis  = 0xff, // U+00ff
+}
+
+impl NonASCIIA02 {
+    /// Unicode equivalent of the ROM character, if there is one.
+    pub const fn to_char(self) -> Option<char> {
+        match self {
+            Self::TrianglePointingRight         => Some('\u{2bc8}'),
+            Self::TrianglePointingLeft          => Some('\u{2bc7}'),
+            Self::QuotationMarkLeftDouble       => Some('\u{201c}'),
+            Self::QuotationMarkRightDouble      => Some('\u{201d}'),
+            Self::TriangleDoubleUp              => Some('\u{23eb}'),
+            Self::TriangleDoubleDown            => Some('\u{23ec}'),
+            Self::BlackLargeCircle              => Some('\u{2b24}'),
+            Self::ArrowDownLeft                 => Some('\u{21b5}'),
+            Self::UpwardsArrow                  => Some('\u{2191}'),
+            Self::DownwardsArrow                => Some('\u{2193}'),
+            Self::RightwardsArrow               => Some('\u{2192}'),
+            Self::LeftwardsArrow                => Some('\u{2190}'),
+            Self::LessThanOrEqual               => Some('\u{2264}'),
+            Self::GreaterThanOrEqual            => Some('\u{2265}'),
+            Self::TrianglePointingUp            => Some('\u{2bc5}'),
+            Self::TrianglePointingDown          => Some('\u{2bc6}'),
+            Self::House                         => Some('\u{2303}'),
+            Self::CyrillicBe                    => Some('\u{0411}'),
+            Self::CyrillicDe                    => Some('\u{0414}'),
+            Self::CyrillicZhe                   => Some('\u{0416}'),
+            Self::CyrillicZe                    => Some('\u{0417}'),
+            Self::CyrillicI                     => Some('\u{0418}'),
+            Self::CirillicShortI                => Some('\u{0419}'),
+            Self::CirillicEl                    => Some('\u{041b}'),
+            Self::CirillicPe                    => Some('\u{041f}'),
+            Self::CyrillicU                     => Some('\u{0423}'),
+            Self::CyrillicTse                   => Some('\u{0426}'),
+            Self::CyrillicChe                   => Some('\u{0427}'),
+            Self::CyrillicSha                   => Some('\u{0428}'),
+            Self::CyrillicShcha                 => Some('\u{0429}'),
+            Self::CyrillicHardSign              => Some('\u{042a}'),
+            Self::CyrillicYeru                  => Some('\u{042b}'),
+            Self::CyrillicE                     => Some('\u{042d}'),
+            Self::GreekSmallAlpha               => Some('\u{03b1}'),
+            Self::EighthNote                    => Some('\u{266a}'),
+            Self::GreekCapitalGamma             => Some('\u{0393}'),
+            Self::GreekSmallPi                  => Some('\u{03c0}'),
+            Self::GreekCapitalSigma             => Some('\u{03a3}'),
+            Self::GreekSmallSigma               => Some('\u{03c3}'),
+            Self::BeamedDecendingNote           => Some('\u{266b}'),
+            Self::GreekSmallTau                 => Some('\u{03c4}'),
+            Self::BellSymbol                    => Some('\u{1f514}'),
+            Self::GreekCapitalTheta             => Some('\u{0398}'),
+            Self::GreekCapitalOmega             => Some('\u{03a9}'),
+            Self::GreekSmallDelta               => Some('\u{03b4}'),
+            Self::Infinity                      => Some('\u{221e}'),
+            Self::BlackHeartSuit                => Some('\u{2665}'),
+            Self::GreekSmallEpsilon             => Some('\u{03b5}'),
+            Self::Intersection                  => Some('\u{2229}'),
+            Self::HeavyVerticalBars             => Some('\u{2016}'),
+            Self::InvertedExclamationMark       => Some('\u{00a1}'),
+            Self::CentSign                      => Some('\u{00a2}'),
+            Self::PoundSign                     => Some('\u{00a3}'),
+            Self::CurrencySign                  => Some('\u{00a4}'),
+            Self::YenSign                       => Some('\u{00a5}'),
+            Self::BrokenBar                     => Some('\u{00a6}'),
+            Self::SectionSign                   => Some('\u{00a7}'),
+            Self::LatinSmallFHook               => Some('\u{0192}'),
+            Self::CopyrightSign                 => Some('\u{00a9}'),
+            Self::LatinSmallAUnderbar           => Some('\u{00aa}'),
+            Self::DoubleAngleQuotationMarkLeft  => Some('\u{00ab}'),
+            Self::CyrillicYu                    => Some('\u{042e}'),
+            Self::CyrillicYa                    => Some('\u{042f}'),
+            Self::RegisteredSign                => Some('\u{00ae}'),
+            Self::LeftSingleQuotationMark       => Some('\u{2018}'),
+            Self::DegreeSign                    => Some('\u{00b0}'),
+            Self::PlusMinusSign                 => Some('\u{00b1}'),
+            Self::SuperscriptTwo                => Some('\u{00b2}'),
+            Self::SuperscriptThree              => Some('\u{00b3}'),
+            Self::PesetaSign                    => Some('\u{20a7}'),
+            Self::MicroSign                     => Some('\u{00b5}'),
+            Self::PilcrowSign                   => Some('\u{00b6}'),
+            Self::MiddleDot                     => Some('\u{00b7}'),
+            Self::GreekSmallOmega               => Some('\u{03c9}'),
+            Self::SuperscriptOne                => Some('\u{00b9}'),
+            Self::LatinSmallOUnderbar           => Some('\u{00ba}'),
+            Self::DoubleAngleQuotationMarkRight => Some('\u{00bb}'),
+            Self::FractionOneQuarter            => Some('\u{00bc}'),
+            Self::FractionOneHalf               => Some('\u{00bd}'),
+            Self::FractionThreeQuarter          => Some('\u{00be}'),
+            Self::InvertedQuestionMark          => Some('\u{00bf}'),
+            Self::LatinCapitalAGrave            => Some('\u{00c0}'),
+            Self::LatinCapitalAAcute            => Some('\u{00c1}'),
+            Self::LatinCapitalACircumflex       => Some('\u{00c2}'),
+            Self::LatinCapitalATilde            => Some('\u{00c3}'),
+            Self::LatinCapitalADiaeresis        => Some('\u{00c4}'),
+            Self::LatinCapitalARingAbove        => Some('\u{00c5}'),
+            Self::LatinCapitalAE                => Some('\u{00c6}'),
+            Self::LatinCapitalCCedilla          => Some('\u{00c7}'),
+            Self::LatinCapitalEGrave            => Some('\u{00c8}'),
+            Self::LatinCapitalEAcute            => Some('\u{00c9}'),
+            Self::LatinCapitalECircumflex       => Some('\u{00ca}'),
+            Self::LatinCapitalEDiaeresis        => Some('\u{00cb}'),
+            Self::LatinCapitalIGrave            => Some('\u{00cc}'),
+            Self::LatinCapitalIAcute            => Some('\u{00cd}'),
+            Self::LatinCapitalICircumflex       => Some('\u{00ce}'),
+            Self::LatinCapitalIDiaeresis        => Some('\u{00cf}'),
+            Self::LatinCapitalEth               => Some('\u{00d0}'),
+            Self::LatinCapitalNTilde            => Some('\u{00d1}'),
+            Self::LatinCapitalOGrave            => Some('\u{00d2}'),
+            Self::LatinCapitalOAcute            => Some('\u{00d3}'),
+            Self::LatinCapitalOCircumflex       => Some('\u{00d4}'),
+            Self::LatinCapitalOTilde            => Some('\u{00d5}'),
+            Self::LatinCapitalODiaeresis        => Some('\u{00d6}'),
+            Self::MultiplicationSign            => Some('\u{00d7}'),
+            Self::LatinCapitalOStroke           => Some('\u{00d8}'),
+            Self::LatinCapitalUGrave            => Some('\u{00d9}'),
+            Self::LatinCapitalUAcute            => Some('\u{00da}'),
+            Self::LatinCapitalUCircumflex       => Some('\u{00db}'),
+            Self::LatinCapitalUDiaeresis        => Some('\u{00dc}'),
+            Self::LatinCapitalYAcute            => Some('\u{00dd}'),
+            Self::LatinCapitalThorn             => Some('\u{00de}'),
+            Self::LatinSmallSharpS              => Some('\u{00df}'),
+            Self::LatinSmallAGrave              => Some('\u{00e0}'),
+            Self::LatinSmallAAcute              => Some('\u{00e1}'),
+            Self::LatinSmallACircumflex         => Some('\u{00e2}'),
+            Self::LatinSmallATilde              => Some('\u{00e3}'),
+            Self::LatinSmallADiaeresis          => Some('\u{00e4}'),
+            Self::LatinSmallARingAbove          => Some('\u{00e5}'),
+            Self::LatinSmallAE                  => Some('\u{00e6}'),
+            Self::LatinSmallCCedilla            => Some('\u{00e7}'),
+            Self::LatinSmallEGrave              => Some('\u{00e8}'),
+            Self::LatinSmallEAcute              => Some('\u{00e9}'),
+            Self::LatinSmallECircumflex         => Some('\u{00ea}'),
+            Self::LatinSmallEDiaeresis          => Some('\u{00eb}'),
+            Self::LatinSmallIGrave              => Some('\u{00ec}'),
+            Self::LatinSmallIAcute              => Some('\u{00ed}'),
+            Self::LatinSmallICircumflex         => Some('\u{00ee}'),
+            Self::LatinSmallIDiaeresis          => Some('\u{00ef}'),
+            Self::LatinSmallEth                 => Some('\u{00f0}'),
+            Self::LatinSmallNTilde              => Some('\u{00f1}'),
+            Self::LatinSmallOGrave              => Some('\u{00f2}'),
+            Self::LatinSmallOAcute              => Some('\u{00f3}'),
+            Self::LatinSmallOCircumflex         => Some('\u{00f4}'),
+            Self::LatinSmallOTilde              => Some('\u{00f5}'),
+            Self::LatinSmallODiaeresis          => Some('\u{00f6}'),
+            Self::DivisionSign                  => Some('\u{00f7}'),
+            Self::LatinSmallOStroke             => Some('\u{00f8}'),
+            Self::LatinSmallUGrave              => Some('\u{00f9}'),
+            Self::LatinSmallUAcute              => Some('\u{00fa}'),
+            Self::LatinSmallUCircumflex         => Some('\u{00fb}'),
+            Self::LatinSmallUDiaeresis          => Some('\u{00fc}'),
+            Self::LatinSmallYAcute              => Some('\u{00fd}'),
+            Self::LatinSmallThorn               => Some('\u{00fe}'),
+            Self::LatinSmallYDiaeresis          => Some('\u{00ff}'),
+        }
+    }
+}
+
+impl TryFrom<u8> for NonASCIIA02 {
+    type Error = u8;
+
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        match code {
+            0x10 => Ok(Self::TrianglePointingRight),
+            0x11 => Ok(Self::TrianglePointingLeft),
+            0x12 => Ok(Self::QuotationMarkLeftDouble),
+            0x13 => Ok(Self::QuotationMarkRightDouble),
+            0x14 => Ok(Self::TriangleDoubleUp),
+            0x15 => Ok(Self::TriangleDoubleDown),
+            0x16 => Ok(Self::BlackLargeCircle),
+            0x17 => Ok(Self::ArrowDownLeft),
+            0x18 => Ok(Self::UpwardsArrow),
+            0x19 => Ok(Self::DownwardsArrow),
+            0x1a => Ok(Self::RightwardsArrow),
+            0x1b => Ok(Self::LeftwardsArrow),
+            0x1c => Ok(Self::LessThanOrEqual),
+            0x1d => Ok(Self::GreaterThanOrEqual),
+            0x1e => Ok(Self::TrianglePointingUp),
+            0x1f => Ok(Self::TrianglePointingDown),
+            0x7f => Ok(Self::House),
+            0x80 => Ok(Self::CyrillicBe),
+            0x81 => Ok(Self::CyrillicDe),
+            0x82 => Ok(Self::CyrillicZhe),
+            0x83 => Ok(Self::CyrillicZe),
+            0x84 => Ok(Self::CyrillicI),
+            0x85 => Ok(Self::CirillicShortI),
+            0x86 => Ok(Self::CirillicEl),
+            0x87 => Ok(Self::CirillicPe),
+            0x88 => Ok(Self::CyrillicU),
+            0x89 => Ok(Self::CyrillicTse),
+            0x8a => Ok(Self::CyrillicChe),
+            0x8b => Ok(Self::CyrillicSha),
+            0x8c => Ok(Self::CyrillicShcha),
+            0x8d => Ok(Self::CyrillicHardSign),
+            0x8e => Ok(Self::CyrillicYeru),
+            0x8f => Ok(Self::CyrillicE),
+            0x90 => Ok(Self::GreekSmallAlpha),
+            0x91 => Ok(Self::EighthNote),
+            0x92 => Ok(Self::GreekCapitalGamma),
+            0x93 => Ok(Self::GreekSmallPi),
+            0x94 => Ok(Self::GreekCapitalSigma),
+            0x95 => Ok(Self::GreekSmallSigma),
+            0x96 => Ok(Self::BeamedDecendingNote),
+            0x97 => Ok(Self::GreekSmallTau),
+            0x98 => Ok(Self::BellSymbol),
+            0x99 => Ok(Self::GreekCapitalTheta),
+            0x9a => Ok(Self::GreekCapitalOmega),
+            0x9b => Ok(Self::GreekSmallDelta),
+            0x9c => Ok(Self::Infinity),
+            0x9d => Ok(Self::BlackHeartSuit),
+            0x9e => Ok(Self::GreekSmallEpsilon),
+            0x9f => Ok(Self::Intersection),
+            0xa0 => Ok(Self::HeavyVerticalBars),
+            0xa1 => Ok(Self::InvertedExclamationMark),
+            0xa2 => Ok(Self::CentSign),
+            0xa3 => Ok(Self::PoundSign),
+            0xa4 => Ok(Self::CurrencySign),
+            0xa5 => Ok(Self::YenSign),
+            0xa6 => Ok(Self::BrokenBar),
+            0xa7 => Ok(Self::SectionSign),
+            0xa8 => Ok(Self::LatinSmallFHook),
+            0xa9 => Ok(Self::CopyrightSign),
+            0xaa => Ok(Self::LatinSmallAUnderbar),
+            0xab => Ok(Self::DoubleAngleQuotationMarkLeft),
+            0xac => Ok(Self::CyrillicYu),
+            0xad => Ok(Self::CyrillicYa),
+            0xae => Ok(Self::RegisteredSign),
+            0xaf => Ok(Self::LeftSingleQuotationMark),
+            0xb0 => Ok(Self::DegreeSign),
+            0xb1 => Ok(Self::PlusMinusSign),
+            0xb2 => Ok(Self::SuperscriptTwo),
+            0xb3 => Ok(Self::SuperscriptThree),
+            0xb4 => Ok(Self::PesetaSign),
+            0xb5 => Ok(Self::MicroSign),
+            0xb6 => Ok(Self::PilcrowSign),
+            0xb7 => Ok(Self::MiddleDot),
+            0xb8 => Ok(Self::GreekSmallOmega),
+            0xb9 => Ok(Self::SuperscriptOne),
+            0xba => Ok(Self::LatinSmallOUnderbar),
+            0xbb => Ok(Self::DoubleAngleQuotationMarkRight),
+            0xbc => Ok(Self::FractionOneQuarter),
+            0xbd => Ok(Self::FractionOneHalf),
+            0xbe => Ok(Self::FractionThreeQuarter),
+            0xbf => Ok(Self::InvertedQuestionMark),
+            0xc0 => Ok(Self::LatinCapitalAGrave),
+            0xc1 => Ok(Self::LatinCapitalAAcute),
+            0xc2 => Ok(Self::LatinCapitalACircumflex),
+            0xc3 => Ok(Self::LatinCapitalATilde),
+            0xc4 => Ok(Self::LatinCapitalADiaeresis),
+            0xc5 => Ok(Self::LatinCapitalARingAbove),
+            0xc6 => Ok(Self::LatinCapitalAE),
+            0xc7 => Ok(Self::LatinCapitalCCedilla),
+            0xc8 => Ok(Self::LatinCapitalEGrave),
+            0xc9 => Ok(Self::LatinCapitalEAcute),
+            0xca => Ok(Self::LatinCapitalECircumflex),
+            0xcb => Ok(Self::LatinCapitalEDiaeresis),
+            0xcc => Ok(Self::LatinCapitalIGrave),
+            0xcd => Ok(Self::LatinCapitalIAcute),
+            0xce => Ok(Self::LatinCapitalICircumflex),
+            0xcf => Ok(Self::LatinCapitalIDiaeresis),
+            0xd0 => Ok(Self::LatinCapitalEth),
+            0xd1 => Ok(Self::LatinCapitalNTilde),
+            0xd2 => Ok(Self::LatinCapitalOGrave),
+            0xd3 => Ok(Self::LatinCapitalOAcute),
+            0xd4 => Ok(Self::LatinCapitalOCircumflex),
+            0xd5 => Ok(Self::LatinCapitalOTilde),
+            0xd6 => Ok(Self::LatinCapitalODiaeresis),
+            0xd7 => Ok(Self::MultiplicationSign),
+            0xd8 => Ok(Self::LatinCapitalOStroke),
+            0xd9 => Ok(Self::LatinCapitalUGrave),
+            0xda => Ok(Self::LatinCapitalUAcute),
+            0xdb => Ok(Self::LatinCapitalUCircumflex),
+            0xdc => Ok(Self::LatinCapitalUDiaeresis),
+            0xdd => Ok(Self::LatinCapitalYAcute),
+            0xde => Ok(Self::LatinCapitalThorn),
+            0xdf => Ok(Self::LatinSmallSharpS),
+            0xe0 => Ok(Self::LatinSmallAGrave),
+            0xe1 => Ok(Self::LatinSmallAAcute),
+            0xe2 => Ok(Self::LatinSmallACircumflex),
+            0xe3 => Ok(Self::LatinSmallATilde),
+            0xe4 => Ok(Self::LatinSmallADiaeresis),
+            0xe5 => Ok(Self::LatinSmallARingAbove),
+            0xe6 => Ok(Self::LatinSmallAE),
+            0xe7 => Ok(Self::LatinSmallCCedilla),
+            0xe8 => Ok(Self::LatinSmallEGrave),
+            0xe9 => Ok(Self::LatinSmallEAcute),
+            0xea => Ok(Self::LatinSmallECircumflex),
+            0xeb => Ok(Self::LatinSmallEDiaeresis),
+            0xec => Ok(Self::LatinSmallIGrave),
+            0xed => Ok(Self::LatinSmallIAcute),
+            0xee => Ok(Self::LatinSmallICircumflex),
+            0xef => Ok(Self::LatinSmallIDiaeresis),
+            0xf0 => Ok(Self::LatinSmallEth),
+            0xf1 => Ok(Self::LatinSmallNTilde),
+            0xf2 => Ok(Self::LatinSmallOGrave),
+            0xf3 => Ok(Self::LatinSmallOAcute),
+            0xf4 => Ok(Self::LatinSmallOCircumflex),
+            0xf5 => Ok(Self::LatinSmallOTilde),
+            0xf6 => Ok(Self::LatinSmallODiaeresis),
+            0xf7 => Ok(Self::DivisionSign),
+            0xf8 => Ok(Self::LatinSmallOStroke),
+            0xf9 => Ok(Self::LatinSmallUGrave),
+            0xfa => Ok(Self::LatinSmallUAcute),
+            0xfb => Ok(Self::LatinSmallUCircumflex),
+            0xfc => Ok(Self::LatinSmallUDiaeresis),
+            0xfd => Ok(Self::LatinSmallYAcute),
+            0xfe => Ok(Self::LatinSmallThorn),
+            0xff => Ok(Self::LatinSmallYDiaeresis),
+            _ => Err(code),
+        }
+    }
+}
+
+impl TryFrom<char> for NonASCIIA02 {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '\u{2bc8}' => Ok(Self::TrianglePointingRight),
+            '\u{2bc7}' => Ok(Self::TrianglePointingLeft),
+            '\u{201c}' => Ok(Self::QuotationMarkLeftDouble),
+            '\u{201d}' => Ok(Self::QuotationMarkRightDouble),
+            '\u{23eb}' => Ok(Self::TriangleDoubleUp),
+            '\u{23ec}' => Ok(Self::TriangleDoubleDown),
+            '\u{2b24}' => Ok(Self::BlackLargeCircle),
+            '\u{21b5}' => Ok(Self::ArrowDownLeft),
+            '\u{2191}' => Ok(Self::UpwardsArrow),
+            '\u{2193}' => Ok(Self::DownwardsArrow),
+            '\u{2192}' => Ok(Self::RightwardsArrow),
+            '\u{2190}' => Ok(Self::LeftwardsArrow),
+            '\u{2264}' => Ok(Self::LessThanOrEqual),
+            '\u{2265}' => Ok(Self::GreaterThanOrEqual),
+            '\u{2bc5}' => Ok(Self::TrianglePointingUp),
+            '\u{2bc6}' => Ok(Self::TrianglePointingDown),
+            '\u{2303}' => Ok(Self::House),
+            '\u{0411}' => Ok(Self::CyrillicBe),
+            '\u{0414}' => Ok(Self::CyrillicDe),
+            '\u{0416}' => Ok(Self::CyrillicZhe),
+            '\u{0417}' => Ok(Self::CyrillicZe),
+            '\u{0418}' => Ok(Self::CyrillicI),
+            '\u{0419}' => Ok(Self::CirillicShortI),
+            '\u{041b}' => Ok(Self::CirillicEl),
+            '\u{041f}' => Ok(Self::CirillicPe),
+            '\u{0423}' => Ok(Self::CyrillicU),
+            '\u{0426}' => Ok(Self::CyrillicTse),
+            '\u{0427}' => Ok(Self::CyrillicChe),
+            '\u{0428}' => Ok(Self::CyrillicSha),
+            '\u{0429}' => Ok(Self::CyrillicShcha),
+            '\u{042a}' => Ok(Self::CyrillicHardSign),
+            '\u{042b}' => Ok(Self::CyrillicYeru),
+            '\u{042d}' => Ok(Self::CyrillicE),
+            '\u{03b1}' => Ok(Self::GreekSmallAlpha),
+            '\u{266a}' => Ok(Self::EighthNote),
+            '\u{0393}' => Ok(Self::GreekCapitalGamma),
+            '\u{03c0}' => Ok(Self::GreekSmallPi),
+            '\u{03a3}' => Ok(Self::GreekCapitalSigma),
+            '\u{03c3}' => Ok(Self::GreekSmallSigma),
+            '\u{266b}' => Ok(Self::BeamedDecendingNote),
+            '\u{03c4}' => Ok(Self::GreekSmallTau),
+            '\u{1f514}' => Ok(Self::BellSymbol),
+            '\u{0398}' => Ok(Self::GreekCapitalTheta),
+            '\u{03a9}' => Ok(Self::GreekCapitalOmega),
+            '\u{03b4}' => Ok(Self::GreekSmallDelta),
+            '\u{221e}' => Ok(Self::Infinity),
+            '\u{2665}' => Ok(Self::BlackHeartSuit),
+            '\u{03b5}' => Ok(Self::GreekSmallEpsilon),
+            '\u{2229}' => Ok(Self::Intersection),
+            '\u{2016}' => Ok(Self::HeavyVerticalBars),
+            '\u{00a1}' => Ok(Self::InvertedExclamationMark),
+            '\u{00a2}' => Ok(Self::CentSign),
+            '\u{00a3}' => Ok(Self::PoundSign),
+            '\u{00a4}' => Ok(Self::CurrencySign),
+            '\u{00a5}' => Ok(Self::YenSign),
+            '\u{00a6}' => Ok(Self::BrokenBar),
+            '\u{00a7}' => Ok(Self::SectionSign),
+            '\u{0192}' => Ok(Self::LatinSmallFHook),
+            '\u{00a9}' => Ok(Self::CopyrightSign),
+            '\u{00aa}' => Ok(Self::LatinSmallAUnderbar),
+            '\u{00ab}' => Ok(Self::DoubleAngleQuotationMarkLeft),
+            '\u{042e}' => Ok(Self::CyrillicYu),
+            '\u{042f}' => Ok(Self::CyrillicYa),
+            '\u{00ae}' => Ok(Self::RegisteredSign),
+            '\u{2018}' => Ok(Self::LeftSingleQuotationMark),
+            '\u{00b0}' => Ok(Self::DegreeSign),
+            '\u{00b1}' => Ok(Self::PlusMinusSign),
+            '\u{00b2}' => Ok(Self::SuperscriptTwo),
+            '\u{00b3}' => Ok(Self::SuperscriptThree),
+            '\u{20a7}' => Ok(Self::PesetaSign),
+            '\u{00b5}' => Ok(Self::MicroSign),
+            '\u{00b6}' => Ok(Self::PilcrowSign),
+            '\u{00b7}' => Ok(Self::MiddleDot),
+            '\u{03c9}' => Ok(Self::GreekSmallOmega),
+            '\u{00b9}' => Ok(Self::SuperscriptOne),
+            '\u{00ba}' => Ok(Self::LatinSmallOUnderbar),
+            '\u{00bb}' => Ok(Self::DoubleAngleQuotationMarkRight),
+            '\u{00bc}' => Ok(Self::FractionOneQuarter),
+            '\u{00bd}' => Ok(Self::FractionOneHalf),
+            '\u{00be}' => Ok(Self::FractionThreeQuarter),
+            '\u{00bf}' => Ok(Self::InvertedQuestionMark),
+            '\u{00c0}' => Ok(Self::LatinCapitalAGrave),
+            '\u{00c1}' => Ok(Self::LatinCapitalAAcute),
+            '\u{00c2}' => Ok(Self::LatinCapitalACircumflex),
+            '\u{00c3}' => Ok(Self::LatinCapitalATilde),
+            '\u{00c4}' => Ok(Self::LatinCapitalADiaeresis),
+            '\u{00c5}' => Ok(Self::LatinCapitalARingAbove),
+            '\u{00c6}' => Ok(Self::LatinCapitalAE),
+            '\u{00c7}' => Ok(Self::LatinCapitalCCedilla),
+            '\u{00c8}' => Ok(Self::LatinCapitalEGrave),
+            '\u{00c9}' => Ok(Self::LatinCapitalEAcute),
+            '\u{00ca}' => Ok(Self::LatinCapitalECircumflex),
+            '\u{00cb}' => Ok(Self::LatinCapitalEDiaeresis),
+            '\u{00cc}' => Ok(Self::LatinCapitalIGrave),
+            '\u{00cd}' => Ok(Self::LatinCapitalIAcute),
+            '\u{00ce}' => Ok(Self::LatinCapitalICircumflex),
+            '\u{00cf}' => Ok(Self::LatinCapitalIDiaeresis),
+            '\u{00d0}' => Ok(Self::LatinCapitalEth),
+            '\u{00d1}' => Ok(Self::LatinCapitalNTilde),
+            '\u{00d2}' => Ok(Self::LatinCapitalOGrave),
+            '\u{00d3}' => Ok(Self::LatinCapitalOAcute),
+            '\u{00d4}' => Ok(Self::LatinCapitalOCircumflex),
+            '\u{00d5}' => Ok(Self::LatinCapitalOTilde),
+            '\u{00d6}' => Ok(Self::LatinCapitalODiaeresis),
+            '\u{00d7}' => Ok(Self::MultiplicationSign),
+            '\u{00d8}' => Ok(Self::LatinCapitalOStroke),
+            '\u{00d9}' => Ok(Self::LatinCapitalUGrave),
+            '\u{00da}' => Ok(Self::LatinCapitalUAcute),
+            '\u{00db}' => Ok(Self::LatinCapitalUCircumflex),
+            '\u{00dc}' => Ok(Self::LatinCapitalUDiaeresis),
+            '\u{00dd}' => Ok(Self::LatinCapitalYAcute),
+            '\u{00de}' => Ok(Self::LatinCapitalThorn),
+            '\u{00df}' => Ok(Self::LatinSmallSharpS),
+            '\u{00e0}' => Ok(Self::LatinSmallAGrave),
+            '\u{00e1}' => Ok(Self::LatinSmallAAcute),
+            '\u{00e2}' => Ok(Self::LatinSmallACircumflex),
+            '\u{00e3}' => Ok(Self::LatinSmallATilde),
+            '\u{00e4}' => Ok(Self::LatinSmallADiaeresis),
+            '\u{00e5}' => Ok(Self::LatinSmallARingAbove),
+            '\u{00e6}' => Ok(Self::LatinSmallAE),
+            '\u{00e7}' => Ok(Self::LatinSmallCCedilla),
+            '\u{00e8}' => Ok(Self::LatinSmallEGrave),
+            '\u{00e9}' => Ok(Self::LatinSmallEAcute),
+            '\u{00ea}' => Ok(Self::LatinSmallECircumflex),
+            '\u{00eb}' => Ok(Self::LatinSmallEDiaeresis),
+            '\u{00ec}' => Ok(Self::LatinSmallIGrave),
+            '\u{00ed}' => Ok(Self::LatinSmallIAcute),
+            '\u{00ee}' => Ok(Self::LatinSmallICircumflex),
+            '\u{00ef}' => Ok(Self::LatinSmallIDiaeresis),
+            '\u{00f0}' => Ok(Self::LatinSmallEth),
+            '\u{00f1}' => Ok(Self::LatinSmallNTilde),
+            '\u{00f2}' => Ok(Self::LatinSmallOGrave),
+            '\u{00f3}' => Ok(Self::LatinSmallOAcute),
+            '\u{00f4}' => Ok(Self::LatinSmallOCircumflex),
+            '\u{00f5}' => Ok(Self::LatinSmallOTilde),
+            '\u{00f6}' => Ok(Self::LatinSmallODiaeresis),
+            '\u{00f7}' => Ok(Self::DivisionSign),
+            '\u{00f8}' => Ok(Self::LatinSmallOStroke),
+            '\u{00f9}' => Ok(Self::LatinSmallUGrave),
+            '\u{00fa}' => Ok(Self::LatinSmallUAcute),
+            '\u{00fb}' => Ok(Self::LatinSmallUCircumflex),
+            '\u{00fc}' => Ok(Self::LatinSmallUDiaeresis),
+            '\u{00fd}' => Ok(Self::LatinSmallYAcute),
+            '\u{00fe}' => Ok(Self::LatinSmallThorn),
+            '\u{00ff}' => Ok(Self::LatinSmallYDiaeresis),
+            _ => Err(c),
+        }
+    }
+}
+
+
+/// Character generator ROM of the display controller, printed on the chip as HD44780U**A00** or **A02**.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Rom {
+    /// Japanese: ASCII (except `\` and `~`), Katakana and some Greek / math symbols
+    A00,
+    /// European: ASCII, Latin-1, Greek and some Cyrillic
+    A02,
+}
+
+impl Rom {
+    /// Character code of `c` in this ROM, `None` if the ROM has no such character.
+    /// The chars `'\u{0}'` to `'\u{7}'` print the custom chars [`CustomFont5x8::ALL`],
+    /// all other control chars, e.g. `'\n'`, `'\r'` and `'\t'`, are not in the ROM.
+    /// Displays with the 5x10 font have only four custom chars, see [`Rom::encode_for`].
+    pub fn encode(&self, c: char) -> Option<u8> {
+        self.encode_for(c, FnsetFont::Dots5x8)
+    }
+
+    /// Like [`Rom::encode`] for a display using `font`. With the 5x10 font `'\u{0}'` to `'\u{3}'`
+    /// print the custom chars [`CustomFont5x10::ALL`] and `'\u{4}'` to `'\u{7}'` have no code.
+    pub fn encode_for(&self, c: char, font: FnsetFont) -> Option<u8> {
+        let custom = match font {
+            FnsetFont::Dots5x8 => CustomFont5x8::ALL.get(c as usize).map(|&custom| custom as u8),
+            FnsetFont::Dots5x10 => CustomFont5x10::ALL.get(c as usize).map(|&custom| custom as u8),
+        };
+        if custom.is_some() {
+            return custom;
+        }
+        match self {
+            Rom::A00 => match c {
+                '\\' | '~' => None,
+                ' '..='}' => Some(c as u8),
+                // half-width forms are in ROM order
+                '\u{ff61}'..='\u{ff9f}' => Some((c as u32 - 0xff61 + 0xa1) as u8),
+                _ => NonASCIIA00::try_from(c).ok().map(|ch| ch as u8),
+            },
+            Rom::A02 => match c {
+                ' '..='~' => Some(c as u8),
+                _ => NonASCIIA02::try_from(c).ok().map(|ch| ch as u8),
+            },
+        }
+    }
+}
+
+
+
+/// There are 8 possible Custom Chars for Font 5x8.
+/// Character codes are: 0b0000*xxx
+/// * has no effect. x bits can be 0 or 1
+/// 
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CustomFont5x8 {
+    Char0=0b0000_1000,
+    Char1=0b0000_1001,
+    Char2=0b0000_1010,
+    Char3=0b0000_1011,
+    Char4=0b0000_1100,
+    Char5=0b0000_1101,
+    Char6=0b0000_1110,
+    Char7=0b0000_1111,
+}
+
+impl CustomFont5x8 {
+    /// All slots in CG RAM order.
+    pub const ALL: [Self; 8] = [
+        Self::Char0, Self::Char1, Self::Char2, Self::Char3,
+        Self::Char4, Self::Char5, Self::Char6, Self::Char7,
+    ];
+
+    // every 5x8 glyph occupies 8 bytes of CG RAM
+    pub(crate) const fn slot(self) -> usize {
+        (self as u8 & 0b0000_0111) as usize
+    }
+}
+
+
+/// Converts one row of a glyph picture to its bit pattern, `'#'` is a set and `'.'` a clear pixel.
+/// Panics unless the row is five pixels wide, inside [`glyph!`](crate::glyph) this is a compile error.
+pub const fn glyph_row(row: &str) -> u8 {
+    let pixels = row.as_bytes();
+    assert!(pixels.len() == 5, "glyph rows are 5 pixels wide");
+    let mut bits = 0;
+    let mut i = 0;
+    while i < pixels.len() {
+        bits <<= 1;
+        match pixels[i] {
+            b'#' => bits |= 1,
+            b'.' => {},
+            _ => panic!("glyph pixels are '#' or '.'"),
+        }
+        i += 1;
+    }
+    bits
+}
+
+/// Builds the charmap for `create_char` from a picture, one string per row:
+/// 8 rows give a `[u8; 8]` for 5x8 fonts and 10 rows a `[u8; 10]` for 5x10 fonts, e.g.
+/// `glyph!(".....", ".#.#.", "#####", "#####", ".###.", "..#..", ".....", ".....")` for a heart.
+/// Rows which are not five pixels of `'#'` and `'.'`, or any other row count, fail to compile.
+#[macro_export]
+macro_rules! glyph {
+    ($($row:expr),+ $(,)?) => {
+        const {
+            let rows = [$($crate::characters::glyph_row($row)),+];
+            assert!(rows.len() == 8 || rows.len() == 10, "glyphs have 8 (5x8) or 10 (5x10) rows");
+            rows
+        }
+    };
+}
+
+/// Bitmap of a custom character, bit 4 of every row is the leftmost pixel.
+/// All transforms are `const` and return a new glyph, pixels moved outside of the 5 pixel wide cell are lost.
+/// `Debug` prints the picture as with [`glyph!`](crate::glyph), one row per line.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Glyph<const ROWS: usize> {
+    rows: [u8; ROWS],
+}
+
+pub type Glyph5x8 = Glyph<8>;
+pub type Glyph5x10 = Glyph<10>;
+
+impl<const ROWS: usize> Glyph<ROWS> {
+    pub const WIDTH: u8 = 5;
+    pub const HEIGHT: u8 = ROWS as u8;
+    const MASK: u8 = 0b1_1111;
+
+    pub const EMPTY: Self = Self { rows: [0; ROWS] };
+    pub const FULL: Self = Self { rows: [Self::MASK; ROWS] };
+
+    /// Bits above the 5 pixels of a row are cleared.
+    pub const fn new(rows: [u8; ROWS]) -> Self {
+        Self::EMPTY.overlay(Self { rows })
+    }
+
+    pub const fn rows(&self) -> [u8; ROWS] {
+        self.rows
+    }
+
+    /// Pixel at column `x` (0 is left) and row `y` (0 is top), `false` outside of the glyph.
+    pub const fn pixel(&self, x: u8, y: u8) -> bool {
+        x < Self::WIDTH && (y as usize) < ROWS && self.rows[y as usize] & (0b1_0000 >> x) != 0
+    }
+
+    /// Sets or clears the pixel at `x`/`y`, nothing happens outside of the glyph.
+    pub const fn set_pixel(&mut self, x: u8, y: u8, on: bool) {
+        if x < Self::WIDTH && (y as usize) < ROWS {
+            let bit = 0b1_0000 >> x;
+            self.rows[y as usize] = if on { self.rows[y as usize] | bit } else { self.rows[y as usize] & !bit };
+        }
+    }
+
+    pub const fn invert(self) -> Self {
+        let mut rows = self.rows;
+        let mut y = 0;
+        while y < ROWS {
+            rows[y] = !rows[y] & Self::MASK;
+            y += 1;
+        }
+        Self { rows }
+    }
+
+    /// Swaps left and right.
+    pub const fn mirror_horizontal(self) -> Self {
+        let mut rows = self.rows;
+        let mut y = 0;
+        while y < ROWS {
+            rows[y] = rows[y].reverse_bits() >> 3;
+            y += 1;
+        }
+        Self { rows }
+    }
+
+    /// Swaps top and bottom.
+    pub const fn mirror_vertical(self) -> Self {
+        let mut rows = self.rows;
+        let mut y = 0;
+        while y < ROWS {
+            rows[y] = self.rows[ROWS - 1 - y];
+            y += 1;
+        }
+        Self { rows }
+    }
+
+    /// Moves all pixels `dx` columns to the right and `dy` rows down, negative values move left and up.
+    pub const fn shift(self, dx: i8, dy: i8) -> Self {
+        let mut rows = [0; ROWS];
+        let mut y = 0;
+        while y < ROWS {
+            let from = y as isize - dy as isize;
+            if from >= 0 && from < ROWS as isize {
+                let row = self.rows[from as usize] as u32;
+                rows[y] = match dx {
+                    0 => row,
+                    1..=4 => row >> dx,
+                    -4..=-1 => row << -dx,
+                    _ => 0,
+                } as u8 & Self::MASK;
+            }
+            y += 1;
+        }
+        Self { rows }
+    }
+
+    /// Pixels set in either glyph.
+    pub const fn overlay(self, other: Self) -> Self {
+        let mut rows = self.rows;
+        let mut y = 0;
+        while y < ROWS {
+            rows[y] = (rows[y] | other.rows[y]) & Self::MASK;
+            y += 1;
+        }
+        Self { rows }
+    }
+
+    /// Keeps the pixels of the `width` x `height` rectangle at `x`/`y` and clears all others.
+    pub const fn crop(self, x: u8, y: u8, width: u8, height: u8) -> Self {
+        let mut window = Self::EMPTY;
+        let mut row = y as usize;
+        while row < ROWS && row < y as usize + height as usize {
+            let mut col = x;
+            while col < Self::WIDTH && (col as usize) < x as usize + width as usize {
+                window.rows[row] |= 0b1_0000 >> col;
+                col += 1;
+            }
+            row += 1;
+        }
+
+        let mut rows = self.rows;
+        let mut i = 0;
+        while i < ROWS {
+            rows[i] &= window.rows[i];
+            i += 1;
+        }
+        Self { rows }
+    }
+}
+
+impl<const ROWS: usize> Default for Glyph<ROWS> {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+impl<const ROWS: usize> From<[u8; ROWS]> for Glyph<ROWS> {
+    fn from(rows: [u8; ROWS]) -> Self {
+        Self::new(rows)
+    }
+}
+
+impl<const ROWS: usize> From<Glyph<ROWS>> for [u8; ROWS] {
+    fn from(glyph: Glyph<ROWS>) -> Self {
+        glyph.rows
+    }
+}
+
+impl<const ROWS: usize> fmt::Debug for Glyph<ROWS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..Self::HEIGHT {
+            if y > 0 {
+                f.write_char('\n')?;
+            }
+            for x in 0..Self::WIDTH {
+                f.write_char(if self.pixel(x, y) { '#' } else { '.' })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Up to eight 5x8 glyphs which are loaded together with `Hd44780::load_charset`,
+/// glyph `i` is then shown by `CustomFont5x8::ALL[i]`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CharSet {
+    name: &'static str,
+    glyphs: [[u8; 8]; 8],
+    len: usize,
+}
+
+impl CharSet {
+    /// Panics with more than eight glyphs, at compile time when used in a `const`.
+    pub const fn new(name: &'static str, glyphs: &[[u8; 8]]) -> Self {
+        assert!(glyphs.len() <= 8, "a CharSet holds at most 8 glyphs");
+        let mut set = [[0; 8]; 8];
+        let mut i = 0;
+        while i < glyphs.len() {
+            set[i] = glyphs[i];
+            i += 1;
+        }
+        Self { name, glyphs: set, len: glyphs.len() }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn glyphs(&self) -> &[[u8; 8]] {
+        &self.glyphs[..self.len]
+    }
+
+    /// Character code showing glyph `index` once the set is loaded.
+    pub fn code(&self, index: usize) -> Option<CustomFont5x8> {
+        if index < self.len { Some(CustomFont5x8::ALL[index]) } else { None }
+    }
+}
+
+/// Text attribute the display cannot show by itself, `print_styled` draws it into custom characters.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum TextAttr {
+    /// Plain ROM characters, no custom character is used
+    #[default]
+    Normal,
+    /// Light text on a dark cell
+    Inverse,
+    /// Line across the bottom row
+    Underline,
+    /// Line through the middle of capitals
+    Strikethrough,
+}
+
+impl TextAttr {
+    /// `glyph` as drawn with this attribute.
+    pub const fn apply<const ROWS: usize>(self, glyph: Glyph<ROWS>) -> Glyph<ROWS> {
+        let mut line = [0; ROWS];
+        match self {
+            Self::Normal => glyph,
+            Self::Inverse => glyph.invert(),
+            Self::Underline => {
+                line[ROWS - 1] = Glyph::<ROWS>::MASK;
+                glyph.overlay(Glyph { rows: line })
+            },
+            Self::Strikethrough => {
+                line[ROWS / 2 - 1] = Glyph::<ROWS>::MASK;
+                glyph.overlay(Glyph { rows: line })
+            },
+        }
+    }
+}
+
+/// Bitmaps of the characters in the ROM, looked up by character code.
+/// Closures `Fn(u8) -> Option<Glyph<ROWS>>` are sources as well.
+pub trait GlyphSource<const ROWS: usize> {
+    /// Glyph of the character `code`, `None` if the source has no bitmap for it.
+    fn glyph(&self, code: u8) -> Option<Glyph<ROWS>>;
+}
+
+impl<const ROWS: usize, F> GlyphSource<ROWS> for F
+where
+    F: Fn(u8) -> Option<Glyph<ROWS>>,
+{
+    fn glyph(&self, code: u8) -> Option<Glyph<ROWS>> {
+        self(code)
+    }
+}
+
+
+/// There are 4 possible Custom Chars for Font 5x10.
+/// Character codes are: 0b0000*xx*
+/// * has no effect. x bits can be 0 or 1
+/// 
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CustomFont5x10 {
+    Char0=0b0000_1001,
+    Char1=0b0000_1011,
+    Char2=0b0000_1101,
+    Char3=0b0000_1111,
+}
+
+impl CustomFont5x10 {
+    /// All slots in CG RAM order.
+    pub const ALL: [Self; 4] = [Self::Char0, Self::Char1, Self::Char2, Self::Char3];
+
+    // every 5x10 glyph occupies 16 bytes of CG RAM, the two codes of a glyph differ in bit 0
+    pub(crate) const fn slot(self) -> usize {
+        ((self as u8 & 0b0000_0110) >> 1) as usize
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_a00() {
+        assert_eq!(Rom::A00.encode('A'), Some(b'A'));
+        assert_eq!(Rom::A00.encode('}'), Some(b'}'));
+        assert_eq!(Rom::A00.encode('¥'), Some(0x5c));
+        assert_eq!(Rom::A00.encode('\\'), None);
+        assert_eq!(Rom::A00.encode('~'), None);
+        assert_eq!(Rom::A00.encode('→'), Some(0x7e));
+        assert_eq!(Rom::A00.encode('ｱ'), Some(0xb1));
+        assert_eq!(Rom::A00.encode('é'), None);
+    }
+
+    #[test]
+    fn encode_a02() {
+        assert_eq!(Rom::A02.encode('A'), Some(b'A'));
+        assert_eq!(Rom::A02.encode('\\'), Some(0x5c));
+        assert_eq!(Rom::A02.encode('~'), Some(0x7e));
+        assert_eq!(Rom::A02.encode('¥'), Some(0xa5));
+        assert_eq!(Rom::A02.encode('Б'), Some(NonASCIIA02::CyrillicBe as u8));
+        assert_eq!(Rom::A02.encode('ｱ'), None);
+    }
+
+    #[test]
+    fn encode_control_chars() {
+        for rom in [Rom::A00, Rom::A02] {
+            assert_eq!(rom.encode('\u{0}'), Some(CustomFont5x8::Char0 as u8));
+            assert_eq!(rom.encode('\u{7}'), Some(CustomFont5x8::Char7 as u8));
+            assert_eq!(rom.encode_for('\u{3}', FnsetFont::Dots5x10), Some(CustomFont5x10::Char3 as u8));
+            assert_eq!(rom.encode_for('\u{4}', FnsetFont::Dots5x10), None);
+            assert_eq!(rom.encode('\t'), None);
+            assert_eq!(rom.encode('\n'), None);
+            assert_eq!(rom.encode('\r'), None);
+        }
+    }
+
+    #[test]
+    fn glyph_macro() {
+        let heart = glyph!(".....", ".#.#.", "#####", "#####", ".###.", "..#..", ".....", ".....");
+        assert_eq!(heart, [0x00, 0x0a, 0x1f, 0x1f, 0x0e, 0x04, 0x00, 0x00]);
+        let tall = glyph!("#....", ".....", ".....", ".....", ".....", ".....", ".....", ".....", ".....", "....#");
+        assert_eq!(tall, [0x10, 0, 0, 0, 0, 0, 0, 0, 0, 0x01]);
+    }
+
+    const ARROW: Glyph5x8 = Glyph::new(glyph!("..#..", ".##..", "#####", ".##..", "..#..", ".....", ".....", "....."));
+
+    #[test]
+    fn glyph_new_masks_rows() {
+        assert_eq!(Glyph::new([0xff; 8]), Glyph5x8::FULL);
+        assert!(ARROW.pixel(0, 2) && !ARROW.pixel(0, 0));
+        assert!(!ARROW.pixel(5, 2) && !ARROW.pixel(0, 8));
+    }
+
+    #[test]
+    fn glyph_transforms() {
+        assert_eq!(ARROW.invert().rows(), [0x1b, 0x13, 0x00, 0x13, 0x1b, 0x1f, 0x1f, 0x1f]);
+        assert_eq!(ARROW.invert().invert(), ARROW);
+        assert_eq!(ARROW.mirror_horizontal().rows(), [0x04, 0x06, 0x1f, 0x06, 0x04, 0, 0, 0]);
+        assert_eq!(ARROW.mirror_vertical().rows(), [0, 0, 0, 0x04, 0x0c, 0x1f, 0x0c, 0x04]);
+        assert_eq!(ARROW.shift(1, 1).rows(), [0, 0x02, 0x06, 0x0f, 0x06, 0x02, 0, 0]);
+        assert_eq!(ARROW.shift(-1, -2).rows(), [0x1e, 0x18, 0x08, 0, 0, 0, 0, 0]);
+        assert_eq!(ARROW.shift(5, 0), Glyph5x8::EMPTY);
+        assert_eq!(ARROW.crop(1, 1, 2, 3).rows(), [0, 0x0c, 0x0c, 0x0c, 0, 0, 0, 0]);
+        assert_eq!(ARROW.overlay(ARROW.mirror_horizontal()).rows(), [0x04, 0x0e, 0x1f, 0x0e, 0x04, 0, 0, 0]);
+    }
+
+    #[test]
+    fn glyph_set_pixel() {
+        let mut glyph = Glyph5x10::EMPTY;
+        glyph.set_pixel(4, 9, true);
+        glyph.set_pixel(5, 0, true);
+        assert_eq!(glyph.rows(), [0, 0, 0, 0, 0, 0, 0, 0, 0, 0x01]);
+        glyph.set_pixel(4, 9, false);
+        assert_eq!(glyph, Glyph5x10::EMPTY);
+    }
+
+    #[test]
+    fn text_attributes() {
+        assert_eq!(TextAttr::Normal.apply(ARROW), ARROW);
+        assert_eq!(TextAttr::Inverse.apply(ARROW), ARROW.invert());
+        assert_eq!(TextAttr::Underline.apply(ARROW).rows()[7], 0x1f);
+        assert_eq!(TextAttr::Strikethrough.apply(ARROW).rows()[3], 0x1f);
+        assert_eq!(TextAttr::Underline.apply(Glyph5x10::EMPTY).rows()[9], 0x1f);
+    }
+
+    #[test]
+    fn char_conversions_are_inverse() {
+        for code in 0..=u8::MAX {
+            if let Some(c) = NonASCIIA00::try_from(code).ok().and_then(NonASCIIA00::to_char) {
+                assert_eq!(NonASCIIA00::try_from(c).map(|ch| ch as u8), Ok(code), "A00 {c}");
+            }
+            if let Some(c) = NonASCIIA02::try_from(code).ok().and_then(NonASCIIA02::to_char) {
+                assert_eq!(NonASCIIA02::try_from(c).map(|ch| ch as u8), Ok(code), "A02 {c}");
+            }
+        }
+        // look-alikes are left to `fallback::lookalike`
+        for c in ['\u{00b0}', '\u{00b5}', '\u{00f1}', '\u{2126}', 'ｱ'] {
+            assert_eq!(NonASCIIA00::try_from(c), Err(c));
+        }
+        assert_eq!(NonASCIIA02::try_from('\u{2126}'), Err('\u{2126}'));
+        assert_eq!(fallback::lookalike('\u{00b0}').and_then(|c| Rom::A00.encode(c)), Some(0xdf));
+        assert_eq!(fallback::lookalike('\u{2126}').and_then(|c| Rom::A02.encode(c)), Some(NonASCIIA02::GreekCapitalOmega as u8));
+    }
+}
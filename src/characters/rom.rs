@@ -0,0 +1,623 @@
+// Pixel data of the character generator ROMs, one 5x8 glyph per code from 0x10 to 0xff.
+// Codes 0x00 to 0x0f show the custom characters in CG RAM and have no ROM glyph.
+// Row 7 is left empty for the cursor except where the ROM draws descenders into it.
+
+use super::{GlyphSource, Glyph, Glyph5x8, Glyph5x10, Rom};
+
+
+const FIRST: u8 = 0x10;
+// A00 has taller glyphs from this code on when the display uses the 5x10 font
+const FIRST_TALL: u8 = 0xe0;
+
+
+impl Rom {
+    /// Glyph of the character `code` as drawn by a display with the 5x8 font,
+    /// `None` for the custom characters 0x00 to 0x0f.
+    pub const fn glyph_5x8(self, code: u8) -> Option<Glyph5x8> {
+        if code < FIRST {
+            return None;
+        }
+        let table = match self {
+            Rom::A00 => &A00_5X8,
+            Rom::A02 => &A02_5X8,
+        };
+        Some(Glyph::new(table[(code - FIRST) as usize]))
+    }
+
+    /// Glyph of the character `code` as drawn by a display with the 5x10 font,
+    /// `None` for the custom characters 0x00 to 0x0f. A02 has no 5x10 font,
+    /// like most A00 characters its glyphs are the 5x8 ones with two empty rows below.
+    pub const fn glyph_5x10(self, code: u8) -> Option<Glyph5x10> {
+        if let Rom::A00 = self && code >= FIRST_TALL {
+            return Some(Glyph::new(A00_5X10[(code - FIRST_TALL) as usize]));
+        }
+        match self.glyph_5x8(code) {
+            Some(glyph) => {
+                let short = glyph.rows();
+                let mut rows = [0; 10];
+                let mut y = 0;
+                while y < short.len() {
+                    rows[y] = short[y];
+                    y += 1;
+                }
+                Some(Glyph::new(rows))
+            },
+            None => None,
+        }
+    }
+}
+
+impl GlyphSource<8> for Rom {
+    fn glyph(&self, code: u8) -> Option<Glyph5x8> {
+        self.glyph_5x8(code)
+    }
+}
+
+impl GlyphSource<10> for Rom {
+    fn glyph(&self, code: u8) -> Option<Glyph5x10> {
+        self.glyph_5x10(code)
+    }
+}
+
+
+const A00_5X8: [[u8; 8]; 240] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x10 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x11 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x12 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x13 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x14 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x15 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x16 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x17 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x18 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x19 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x1a empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x1b empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x1c empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x1d empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x1e empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x1f empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x20 space
+    [0x04, 0x04, 0x04, 0x04, 0x00, 0x00, 0x04, 0x00], // 0x21 !
+    [0x0a, 0x0a, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x22 "
+    [0x0a, 0x0a, 0x1f, 0x0a, 0x1f, 0x0a, 0x0a, 0x00], // 0x23 #
+    [0x04, 0x0f, 0x14, 0x0e, 0x05, 0x1e, 0x04, 0x00], // 0x24 $
+    [0x18, 0x19, 0x02, 0x04, 0x08, 0x13, 0x03, 0x00], // 0x25 %
+    [0x0c, 0x12, 0x14, 0x08, 0x15, 0x12, 0x0d, 0x00], // 0x26 &
+    [0x0c, 0x04, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x27 '
+    [0x02, 0x04, 0x08, 0x08, 0x08, 0x04, 0x02, 0x00], // 0x28 (
+    [0x08, 0x04, 0x02, 0x02, 0x02, 0x04, 0x08, 0x00], // 0x29 )
+    [0x00, 0x04, 0x15, 0x0e, 0x15, 0x04, 0x00, 0x00], // 0x2a *
+    [0x00, 0x04, 0x04, 0x1f, 0x04, 0x04, 0x00, 0x00], // 0x2b +
+    [0x00, 0x00, 0x00, 0x00, 0x0c, 0x04, 0x08, 0x00], // 0x2c ,
+    [0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x00], // 0x2d -
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x0c, 0x00], // 0x2e .
+    [0x00, 0x01, 0x02, 0x04, 0x08, 0x10, 0x00, 0x00], // 0x2f /
+    [0x0e, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0e, 0x00], // 0x30 0
+    [0x04, 0x0c, 0x04, 0x04, 0x04, 0x04, 0x0e, 0x00], // 0x31 1
+    [0x0e, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1f, 0x00], // 0x32 2
+    [0x1f, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0e, 0x00], // 0x33 3
+    [0x02, 0x06, 0x0a, 0x12, 0x1f, 0x02, 0x02, 0x00], // 0x34 4
+    [0x1f, 0x10, 0x1e, 0x01, 0x01, 0x11, 0x0e, 0x00], // 0x35 5
+    [0x06, 0x08, 0x10, 0x1e, 0x11, 0x11, 0x0e, 0x00], // 0x36 6
+    [0x1f, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08, 0x00], // 0x37 7
+    [0x0e, 0x11, 0x11, 0x0e, 0x11, 0x11, 0x0e, 0x00], // 0x38 8
+    [0x0e, 0x11, 0x11, 0x0f, 0x01, 0x02, 0x0c, 0x00], // 0x39 9
+    [0x00, 0x0c, 0x0c, 0x00, 0x0c, 0x0c, 0x00, 0x00], // 0x3a :
+    [0x00, 0x0c, 0x0c, 0x00, 0x0c, 0x04, 0x08, 0x00], // 0x3b ;
+    [0x02, 0x04, 0x08, 0x10, 0x08, 0x04, 0x02, 0x00], // 0x3c <
+    [0x00, 0x00, 0x1f, 0x00, 0x1f, 0x00, 0x00, 0x00], // 0x3d =
+    [0x08, 0x04, 0x02, 0x01, 0x02, 0x04, 0x08, 0x00], // 0x3e >
+    [0x0e, 0x11, 0x01, 0x02, 0x04, 0x00, 0x04, 0x00], // 0x3f ?
+    [0x0e, 0x11, 0x01, 0x0d, 0x15, 0x15, 0x0e, 0x00], // 0x40 @
+    [0x0e, 0x11, 0x11, 0x11, 0x1f, 0x11, 0x11, 0x00], // 0x41 A
+    [0x1e, 0x11, 0x11, 0x1e, 0x11, 0x11, 0x1e, 0x00], // 0x42 B
+    [0x0e, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0e, 0x00], // 0x43 C
+    [0x1c, 0x12, 0x11, 0x11, 0x11, 0x12, 0x1c, 0x00], // 0x44 D
+    [0x1f, 0x10, 0x10, 0x1e, 0x10, 0x10, 0x1f, 0x00], // 0x45 E
+    [0x1f, 0x10, 0x10, 0x1e, 0x10, 0x10, 0x10, 0x00], // 0x46 F
+    [0x0e, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0f, 0x00], // 0x47 G
+    [0x11, 0x11, 0x11, 0x1f, 0x11, 0x11, 0x11, 0x00], // 0x48 H
+    [0x0e, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0e, 0x00], // 0x49 I
+    [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0c, 0x00], // 0x4a J
+    [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11, 0x00], // 0x4b K
+    [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1f, 0x00], // 0x4c L
+    [0x11, 0x1b, 0x15, 0x15, 0x11, 0x11, 0x11, 0x00], // 0x4d M
+    [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11, 0x00], // 0x4e N
+    [0x0e, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0x4f O
+    [0x1e, 0x11, 0x11, 0x1e, 0x10, 0x10, 0x10, 0x00], // 0x50 P
+    [0x0e, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0d, 0x00], // 0x51 Q
+    [0x1e, 0x11, 0x11, 0x1e, 0x14, 0x12, 0x11, 0x00], // 0x52 R
+    [0x0f, 0x10, 0x10, 0x0e, 0x01, 0x01, 0x1e, 0x00], // 0x53 S
+    [0x1f, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x00], // 0x54 T
+    [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0x55 U
+    [0x11, 0x11, 0x11, 0x11, 0x11, 0x0a, 0x04, 0x00], // 0x56 V
+    [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0a, 0x00], // 0x57 W
+    [0x11, 0x11, 0x0a, 0x04, 0x0a, 0x11, 0x11, 0x00], // 0x58 X
+    [0x11, 0x11, 0x11, 0x0a, 0x04, 0x04, 0x04, 0x00], // 0x59 Y
+    [0x1f, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1f, 0x00], // 0x5a Z
+    [0x0e, 0x08, 0x08, 0x08, 0x08, 0x08, 0x0e, 0x00], // 0x5b [
+    [0x11, 0x0a, 0x1f, 0x04, 0x1f, 0x04, 0x04, 0x00], // 0x5c yen sign
+    [0x0e, 0x02, 0x02, 0x02, 0x02, 0x02, 0x0e, 0x00], // 0x5d ]
+    [0x04, 0x0a, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x5e ^
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1f, 0x00], // 0x5f _
+    [0x08, 0x04, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x60 `
+    [0x00, 0x00, 0x0e, 0x01, 0x0f, 0x11, 0x0f, 0x00], // 0x61 a
+    [0x10, 0x10, 0x16, 0x19, 0x11, 0x11, 0x1e, 0x00], // 0x62 b
+    [0x00, 0x00, 0x0e, 0x10, 0x10, 0x11, 0x0e, 0x00], // 0x63 c
+    [0x01, 0x01, 0x0d, 0x13, 0x11, 0x11, 0x0f, 0x00], // 0x64 d
+    [0x00, 0x00, 0x0e, 0x11, 0x1f, 0x10, 0x0e, 0x00], // 0x65 e
+    [0x06, 0x09, 0x08, 0x1c, 0x08, 0x08, 0x08, 0x00], // 0x66 f
+    [0x00, 0x0f, 0x11, 0x11, 0x0f, 0x01, 0x0e, 0x00], // 0x67 g
+    [0x10, 0x10, 0x16, 0x19, 0x11, 0x11, 0x11, 0x00], // 0x68 h
+    [0x04, 0x00, 0x0c, 0x04, 0x04, 0x04, 0x0e, 0x00], // 0x69 i
+    [0x02, 0x00, 0x06, 0x02, 0x02, 0x12, 0x0c, 0x00], // 0x6a j
+    [0x10, 0x10, 0x12, 0x14, 0x18, 0x14, 0x12, 0x00], // 0x6b k
+    [0x0c, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0e, 0x00], // 0x6c l
+    [0x00, 0x00, 0x1a, 0x15, 0x15, 0x11, 0x11, 0x00], // 0x6d m
+    [0x00, 0x00, 0x16, 0x19, 0x11, 0x11, 0x11, 0x00], // 0x6e n
+    [0x00, 0x00, 0x0e, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0x6f o
+    [0x00, 0x00, 0x1e, 0x11, 0x1e, 0x10, 0x10, 0x00], // 0x70 p
+    [0x00, 0x00, 0x0d, 0x13, 0x0f, 0x01, 0x01, 0x00], // 0x71 q
+    [0x00, 0x00, 0x16, 0x19, 0x10, 0x10, 0x10, 0x00], // 0x72 r
+    [0x00, 0x00, 0x0e, 0x10, 0x0e, 0x01, 0x1e, 0x00], // 0x73 s
+    [0x08, 0x08, 0x1c, 0x08, 0x08, 0x09, 0x06, 0x00], // 0x74 t
+    [0x00, 0x00, 0x11, 0x11, 0x11, 0x13, 0x0d, 0x00], // 0x75 u
+    [0x00, 0x00, 0x11, 0x11, 0x11, 0x0a, 0x04, 0x00], // 0x76 v
+    [0x00, 0x00, 0x11, 0x11, 0x15, 0x15, 0x0a, 0x00], // 0x77 w
+    [0x00, 0x00, 0x11, 0x0a, 0x04, 0x0a, 0x11, 0x00], // 0x78 x
+    [0x00, 0x00, 0x11, 0x11, 0x0f, 0x01, 0x0e, 0x00], // 0x79 y
+    [0x00, 0x00, 0x1f, 0x02, 0x04, 0x08, 0x1f, 0x00], // 0x7a z
+    [0x02, 0x04, 0x04, 0x08, 0x04, 0x04, 0x02, 0x00], // 0x7b {
+    [0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x00], // 0x7c |
+    [0x08, 0x04, 0x04, 0x02, 0x04, 0x04, 0x08, 0x00], // 0x7d }
+    [0x00, 0x04, 0x02, 0x1f, 0x02, 0x04, 0x00, 0x00], // 0x7e rightwards arrow
+    [0x00, 0x04, 0x08, 0x1f, 0x08, 0x04, 0x00, 0x00], // 0x7f leftwards arrow
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x80 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x81 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x82 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x83 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x84 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x85 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x86 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x87 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x88 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x89 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x8a empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x8b empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x8c empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x8d empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x8e empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x8f empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x90 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x91 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x92 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x93 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x94 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x95 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x96 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x97 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x98 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x99 empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x9a empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x9b empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x9c empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x9d empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x9e empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x9f empty
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0xa0 empty
+    [0x00, 0x00, 0x00, 0x00, 0x1c, 0x14, 0x1c, 0x00], // 0xa1 ideographic full stop
+    [0x07, 0x04, 0x04, 0x04, 0x00, 0x00, 0x00, 0x00], // 0xa2 left corner bracket
+    [0x00, 0x00, 0x00, 0x04, 0x04, 0x04, 0x1c, 0x00], // 0xa3 right corner bracket
+    [0x00, 0x00, 0x00, 0x00, 0x10, 0x08, 0x04, 0x00], // 0xa4 ideographic comma
+    [0x00, 0x00, 0x00, 0x0c, 0x0c, 0x00, 0x00, 0x00], // 0xa5 katakana middle dot
+    [0x00, 0x1f, 0x01, 0x1f, 0x01, 0x02, 0x04, 0x00], // 0xa6 katakana wo
+    [0x00, 0x00, 0x1f, 0x01, 0x06, 0x04, 0x08, 0x00], // 0xa7 katakana small a
+    [0x00, 0x00, 0x02, 0x04, 0x0c, 0x14, 0x04, 0x00], // 0xa8 katakana small i
+    [0x00, 0x00, 0x04, 0x1f, 0x11, 0x01, 0x06, 0x00], // 0xa9 katakana small u
+    [0x00, 0x00, 0x00, 0x1f, 0x04, 0x04, 0x1f, 0x00], // 0xaa katakana small e
+    [0x00, 0x00, 0x02, 0x1f, 0x06, 0x0a, 0x12, 0x00], // 0xab katakana small o
+    [0x00, 0x00, 0x08, 0x1f, 0x09, 0x0a, 0x08, 0x00], // 0xac katakana small ya
+    [0x00, 0x00, 0x00, 0x0e, 0x02, 0x02, 0x1f, 0x00], // 0xad katakana small yu
+    [0x00, 0x00, 0x1e, 0x02, 0x1e, 0x02, 0x1e, 0x00], // 0xae katakana small yo
+    [0x00, 0x00, 0x00, 0x15, 0x15, 0x01, 0x06, 0x00], // 0xaf katakana small tu
+    [0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x00], // 0xb0 prolonged sound mark
+    [0x1f, 0x01, 0x05, 0x06, 0x04, 0x04, 0x08, 0x00], // 0xb1 katakana a
+    [0x01, 0x02, 0x04, 0x0c, 0x14, 0x04, 0x04, 0x00], // 0xb2 katakana i
+    [0x04, 0x1f, 0x11, 0x11, 0x01, 0x02, 0x04, 0x00], // 0xb3 katakana u
+    [0x00, 0x1f, 0x04, 0x04, 0x04, 0x04, 0x1f, 0x00], // 0xb4 katakana e
+    [0x02, 0x1f, 0x02, 0x06, 0x0a, 0x12, 0x02, 0x00], // 0xb5 katakana o
+    [0x08, 0x1f, 0x09, 0x09, 0x09, 0x09, 0x12, 0x00], // 0xb6 katakana ka
+    [0x04, 0x1f, 0x04, 0x1f, 0x04, 0x04, 0x04, 0x00], // 0xb7 katakana ki
+    [0x00, 0x0f, 0x09, 0x11, 0x01, 0x02, 0x0c, 0x00], // 0xb8 katakana ku
+    [0x08, 0x0f, 0x12, 0x02, 0x02, 0x02, 0x04, 0x00], // 0xb9 katakana ke
+    [0x00, 0x1f, 0x01, 0x01, 0x01, 0x01, 0x1f, 0x00], // 0xba katakana ko
+    [0x0a, 0x1f, 0x0a, 0x0a, 0x02, 0x04, 0x08, 0x00], // 0xbb katakana sa
+    [0x00, 0x18, 0x01, 0x19, 0x01, 0x02, 0x1c, 0x00], // 0xbc katakana si
+    [0x00, 0x1f, 0x01, 0x02, 0x04, 0x0a, 0x11, 0x00], // 0xbd katakana su
+    [0x08, 0x1f, 0x09, 0x0a, 0x08, 0x08, 0x07, 0x00], // 0xbe katakana se
+    [0x00, 0x11, 0x11, 0x09, 0x01, 0x02, 0x0c, 0x00], // 0xbf katakana so
+    [0x00, 0x0f, 0x09, 0x17, 0x01, 0x02, 0x0c, 0x00], // 0xc0 katakana ta
+    [0x02, 0x1c, 0x04, 0x1f, 0x04, 0x04, 0x08, 0x00], // 0xc1 katakana ti
+    [0x00, 0x15, 0x15, 0x15, 0x01, 0x02, 0x04, 0x00], // 0xc2 katakana tu
+    [0x0e, 0x00, 0x1f, 0x04, 0x04, 0x04, 0x08, 0x00], // 0xc3 katakana te
+    [0x08, 0x08, 0x08, 0x0c, 0x0a, 0x08, 0x08, 0x00], // 0xc4 katakana to
+    [0x04, 0x04, 0x1f, 0x04, 0x04, 0x08, 0x10, 0x00], // 0xc5 katakana na
+    [0x00, 0x0e, 0x00, 0x00, 0x00, 0x00, 0x1f, 0x00], // 0xc6 katakana ni
+    [0x00, 0x1f, 0x01, 0x0a, 0x04, 0x0a, 0x10, 0x00], // 0xc7 katakana nu
+    [0x04, 0x1f, 0x02, 0x04, 0x0e, 0x15, 0x04, 0x00], // 0xc8 katakana ne
+    [0x02, 0x02, 0x02, 0x02, 0x04, 0x08, 0x10, 0x00], // 0xc9 katakana no
+    [0x00, 0x04, 0x02, 0x11, 0x11, 0x11, 0x11, 0x00], // 0xca katakana ha
+    [0x10, 0x10, 0x1f, 0x10, 0x10, 0x10, 0x0f, 0x00], // 0xcb katakana hi
+    [0x00, 0x1f, 0x01, 0x01, 0x02, 0x04, 0x08, 0x00], // 0xcc katakana hu
+    [0x00, 0x08, 0x14, 0x02, 0x01, 0x01, 0x00, 0x00], // 0xcd katakana he
+    [0x04, 0x1f, 0x04, 0x04, 0x15, 0x15, 0x04, 0x00], // 0xce katakana ho
+    [0x00, 0x1f, 0x01, 0x01, 0x0a, 0x04, 0x02, 0x00], // 0xcf katakana ma
+    [0x00, 0x0e, 0x00, 0x0e, 0x00, 0x0e, 0x01, 0x00], // 0xd0 katakana mi
+    [0x00, 0x04, 0x08, 0x10, 0x11, 0x1f, 0x01, 0x00], // 0xd1 katakana mu
+    [0x00, 0x01, 0x01, 0x0a, 0x04, 0x0a, 0x10, 0x00], // 0xd2 katakana me
+    [0x00, 0x1f, 0x08, 0x1f, 0x08, 0x08, 0x07, 0x00], // 0xd3 katakana mo
+    [0x08, 0x08, 0x1f, 0x09, 0x0a, 0x08, 0x08, 0x00], // 0xd4 katakana ya
+    [0x00, 0x0e, 0x02, 0x02, 0x02, 0x02, 0x1f, 0x00], // 0xd5 katakana yu
+    [0x00, 0x1f, 0x01, 0x1f, 0x01, 0x01, 0x1f, 0x00], // 0xd6 katakana yo
+    [0x0e, 0x00, 0x1f, 0x01, 0x01, 0x02, 0x04, 0x00], // 0xd7 katakana ra
+    [0x12, 0x12, 0x12, 0x12, 0x02, 0x04, 0x08, 0x00], // 0xd8 katakana ri
+    [0x00, 0x04, 0x14, 0x14, 0x15, 0x15, 0x16, 0x00], // 0xd9 katakana ru
+    [0x00, 0x10, 0x10, 0x11, 0x12, 0x14, 0x18, 0x00], // 0xda katakana re
+    [0x00, 0x1f, 0x11, 0x11, 0x11, 0x11, 0x1f, 0x00], // 0xdb katakana ro
+    [0x00, 0x1f, 0x11, 0x11, 0x01, 0x02, 0x04, 0x00], // 0xdc katakana wa
+    [0x00, 0x18, 0x01, 0x01, 0x01, 0x02, 0x1c, 0x00], // 0xdd katakana n
+    [0x04, 0x12, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00], // 0xde voiced sound mark
+    [0x1c, 0x14, 0x1c, 0x00, 0x00, 0x00, 0x00, 0x00], // 0xdf semi-voiced sound mark
+    [0x00, 0x00, 0x09, 0x15, 0x12, 0x12, 0x0d, 0x00], // 0xe0 greek small alpha
+    [0x0a, 0x00, 0x0e, 0x01, 0x0f, 0x11, 0x0f, 0x00], // 0xe1 a diaeresis
+    [0x00, 0x00, 0x0e, 0x11, 0x1e, 0x11, 0x1e, 0x10], // 0xe2 greek small beta
+    [0x00, 0x00, 0x0e, 0x10, 0x0c, 0x11, 0x0e, 0x00], // 0xe3 greek small epsilon
+    [0x00, 0x00, 0x11, 0x11, 0x11, 0x13, 0x1d, 0x10], // 0xe4 greek small mu
+    [0x00, 0x00, 0x0f, 0x14, 0x11, 0x11, 0x0e, 0x00], // 0xe5 greek small sigma
+    [0x00, 0x00, 0x06, 0x09, 0x11, 0x11, 0x1e, 0x10], // 0xe6 greek small rho
+    [0x00, 0x00, 0x0f, 0x11, 0x11, 0x0f, 0x01, 0x0e], // 0xe7 g with descender
+    [0x00, 0x00, 0x07, 0x04, 0x04, 0x14, 0x08, 0x00], // 0xe8 square root
+    [0x00, 0x02, 0x1b, 0x02, 0x00, 0x00, 0x00, 0x00], // 0xe9 superscript minus one
+    [0x00, 0x01, 0x00, 0x06, 0x02, 0x02, 0x12, 0x0c], // 0xea j with descender
+    [0x00, 0x14, 0x08, 0x14, 0x00, 0x00, 0x00, 0x00], // 0xeb superscript x
+    [0x00, 0x04, 0x0e, 0x14, 0x15, 0x0e, 0x04, 0x00], // 0xec cent sign
+    [0x08, 0x08, 0x1c, 0x08, 0x1c, 0x08, 0x0f, 0x00], // 0xed pound sign
+    [0x0e, 0x00, 0x16, 0x19, 0x11, 0x11, 0x11, 0x00], // 0xee n with line above
+    [0x0a, 0x00, 0x0e, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0xef o diaeresis
+    [0x00, 0x00, 0x16, 0x19, 0x11, 0x1e, 0x10, 0x10], // 0xf0 p with descender
+    [0x00, 0x00, 0x0d, 0x13, 0x11, 0x0f, 0x01, 0x01], // 0xf1 q with descender
+    [0x00, 0x0e, 0x11, 0x1f, 0x11, 0x11, 0x0e, 0x00], // 0xf2 greek small theta
+    [0x00, 0x00, 0x00, 0x0a, 0x15, 0x0a, 0x00, 0x00], // 0xf3 infinity
+    [0x00, 0x0e, 0x11, 0x11, 0x11, 0x0a, 0x1b, 0x00], // 0xf4 greek capital omega
+    [0x0a, 0x00, 0x11, 0x11, 0x11, 0x13, 0x0d, 0x00], // 0xf5 u diaeresis
+    [0x1f, 0x10, 0x08, 0x04, 0x08, 0x10, 0x1f, 0x00], // 0xf6 greek capital sigma
+    [0x00, 0x00, 0x1f, 0x0a, 0x0a, 0x0a, 0x13, 0x00], // 0xf7 greek small pi
+    [0x1f, 0x00, 0x11, 0x0a, 0x04, 0x0a, 0x11, 0x00], // 0xf8 x bar
+    [0x00, 0x00, 0x11, 0x11, 0x11, 0x0f, 0x01, 0x0e], // 0xf9 y with descender
+    [0x01, 0x1e, 0x04, 0x1f, 0x04, 0x04, 0x04, 0x00], // 0xfa cjk thousand
+    [0x00, 0x1f, 0x08, 0x0f, 0x09, 0x11, 0x13, 0x00], // 0xfb cjk ten thousand
+    [0x1f, 0x15, 0x15, 0x1f, 0x11, 0x11, 0x13, 0x00], // 0xfc cjk yen
+    [0x00, 0x04, 0x00, 0x1f, 0x00, 0x04, 0x00, 0x00], // 0xfd division sign
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0xfe empty
+    [0x1f, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f], // 0xff all black
+];
+
+const A02_5X8: [[u8; 8]; 240] = [
+    [0x08, 0x0c, 0x0e, 0x0f, 0x0e, 0x0c, 0x08, 0x00], // 0x10 triangle pointing right
+    [0x02, 0x06, 0x0e, 0x1e, 0x0e, 0x06, 0x02, 0x00], // 0x11 triangle pointing left
+    [0x09, 0x12, 0x1b, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x12 left double quotation mark
+    [0x1b, 0x09, 0x12, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x13 right double quotation mark
+    [0x04, 0x0e, 0x1f, 0x00, 0x04, 0x0e, 0x1f, 0x00], // 0x14 double triangle up
+    [0x1f, 0x0e, 0x04, 0x00, 0x1f, 0x0e, 0x04, 0x00], // 0x15 double triangle down
+    [0x00, 0x0e, 0x1f, 0x1f, 0x1f, 0x0e, 0x00, 0x00], // 0x16 black large circle
+    [0x01, 0x01, 0x05, 0x09, 0x1f, 0x08, 0x04, 0x00], // 0x17 enter sign
+    [0x04, 0x0e, 0x15, 0x04, 0x04, 0x04, 0x04, 0x00], // 0x18 upwards arrow
+    [0x04, 0x04, 0x04, 0x04, 0x15, 0x0e, 0x04, 0x00], // 0x19 downwards arrow
+    [0x00, 0x04, 0x02, 0x1f, 0x02, 0x04, 0x00, 0x00], // 0x1a rightwards arrow
+    [0x00, 0x04, 0x08, 0x1f, 0x08, 0x04, 0x00, 0x00], // 0x1b leftwards arrow
+    [0x02, 0x04, 0x08, 0x04, 0x02, 0x00, 0x1f, 0x00], // 0x1c less-than or equal
+    [0x08, 0x04, 0x02, 0x04, 0x08, 0x00, 0x1f, 0x00], // 0x1d greater-than or equal
+    [0x00, 0x04, 0x04, 0x0e, 0x0e, 0x1f, 0x00, 0x00], // 0x1e triangle pointing up
+    [0x00, 0x1f, 0x0e, 0x0e, 0x04, 0x04, 0x00, 0x00], // 0x1f triangle pointing down
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x20 space
+    [0x04, 0x04, 0x04, 0x04, 0x00, 0x00, 0x04, 0x00], // 0x21 !
+    [0x0a, 0x0a, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x22 "
+    [0x0a, 0x0a, 0x1f, 0x0a, 0x1f, 0x0a, 0x0a, 0x00], // 0x23 #
+    [0x04, 0x0f, 0x14, 0x0e, 0x05, 0x1e, 0x04, 0x00], // 0x24 $
+    [0x18, 0x19, 0x02, 0x04, 0x08, 0x13, 0x03, 0x00], // 0x25 %
+    [0x0c, 0x12, 0x14, 0x08, 0x15, 0x12, 0x0d, 0x00], // 0x26 &
+    [0x0c, 0x04, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x27 '
+    [0x02, 0x04, 0x08, 0x08, 0x08, 0x04, 0x02, 0x00], // 0x28 (
+    [0x08, 0x04, 0x02, 0x02, 0x02, 0x04, 0x08, 0x00], // 0x29 )
+    [0x00, 0x04, 0x15, 0x0e, 0x15, 0x04, 0x00, 0x00], // 0x2a *
+    [0x00, 0x04, 0x04, 0x1f, 0x04, 0x04, 0x00, 0x00], // 0x2b +
+    [0x00, 0x00, 0x00, 0x00, 0x0c, 0x04, 0x08, 0x00], // 0x2c ,
+    [0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x00], // 0x2d -
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x0c, 0x00], // 0x2e .
+    [0x00, 0x01, 0x02, 0x04, 0x08, 0x10, 0x00, 0x00], // 0x2f /
+    [0x0e, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0e, 0x00], // 0x30 0
+    [0x04, 0x0c, 0x04, 0x04, 0x04, 0x04, 0x0e, 0x00], // 0x31 1
+    [0x0e, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1f, 0x00], // 0x32 2
+    [0x1f, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0e, 0x00], // 0x33 3
+    [0x02, 0x06, 0x0a, 0x12, 0x1f, 0x02, 0x02, 0x00], // 0x34 4
+    [0x1f, 0x10, 0x1e, 0x01, 0x01, 0x11, 0x0e, 0x00], // 0x35 5
+    [0x06, 0x08, 0x10, 0x1e, 0x11, 0x11, 0x0e, 0x00], // 0x36 6
+    [0x1f, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08, 0x00], // 0x37 7
+    [0x0e, 0x11, 0x11, 0x0e, 0x11, 0x11, 0x0e, 0x00], // 0x38 8
+    [0x0e, 0x11, 0x11, 0x0f, 0x01, 0x02, 0x0c, 0x00], // 0x39 9
+    [0x00, 0x0c, 0x0c, 0x00, 0x0c, 0x0c, 0x00, 0x00], // 0x3a :
+    [0x00, 0x0c, 0x0c, 0x00, 0x0c, 0x04, 0x08, 0x00], // 0x3b ;
+    [0x02, 0x04, 0x08, 0x10, 0x08, 0x04, 0x02, 0x00], // 0x3c <
+    [0x00, 0x00, 0x1f, 0x00, 0x1f, 0x00, 0x00, 0x00], // 0x3d =
+    [0x08, 0x04, 0x02, 0x01, 0x02, 0x04, 0x08, 0x00], // 0x3e >
+    [0x0e, 0x11, 0x01, 0x02, 0x04, 0x00, 0x04, 0x00], // 0x3f ?
+    [0x0e, 0x11, 0x01, 0x0d, 0x15, 0x15, 0x0e, 0x00], // 0x40 @
+    [0x0e, 0x11, 0x11, 0x11, 0x1f, 0x11, 0x11, 0x00], // 0x41 A
+    [0x1e, 0x11, 0x11, 0x1e, 0x11, 0x11, 0x1e, 0x00], // 0x42 B
+    [0x0e, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0e, 0x00], // 0x43 C
+    [0x1c, 0x12, 0x11, 0x11, 0x11, 0x12, 0x1c, 0x00], // 0x44 D
+    [0x1f, 0x10, 0x10, 0x1e, 0x10, 0x10, 0x1f, 0x00], // 0x45 E
+    [0x1f, 0x10, 0x10, 0x1e, 0x10, 0x10, 0x10, 0x00], // 0x46 F
+    [0x0e, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0f, 0x00], // 0x47 G
+    [0x11, 0x11, 0x11, 0x1f, 0x11, 0x11, 0x11, 0x00], // 0x48 H
+    [0x0e, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0e, 0x00], // 0x49 I
+    [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0c, 0x00], // 0x4a J
+    [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11, 0x00], // 0x4b K
+    [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1f, 0x00], // 0x4c L
+    [0x11, 0x1b, 0x15, 0x15, 0x11, 0x11, 0x11, 0x00], // 0x4d M
+    [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11, 0x00], // 0x4e N
+    [0x0e, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0x4f O
+    [0x1e, 0x11, 0x11, 0x1e, 0x10, 0x10, 0x10, 0x00], // 0x50 P
+    [0x0e, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0d, 0x00], // 0x51 Q
+    [0x1e, 0x11, 0x11, 0x1e, 0x14, 0x12, 0x11, 0x00], // 0x52 R
+    [0x0f, 0x10, 0x10, 0x0e, 0x01, 0x01, 0x1e, 0x00], // 0x53 S
+    [0x1f, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x00], // 0x54 T
+    [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0x55 U
+    [0x11, 0x11, 0x11, 0x11, 0x11, 0x0a, 0x04, 0x00], // 0x56 V
+    [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0a, 0x00], // 0x57 W
+    [0x11, 0x11, 0x0a, 0x04, 0x0a, 0x11, 0x11, 0x00], // 0x58 X
+    [0x11, 0x11, 0x11, 0x0a, 0x04, 0x04, 0x04, 0x00], // 0x59 Y
+    [0x1f, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1f, 0x00], // 0x5a Z
+    [0x0e, 0x08, 0x08, 0x08, 0x08, 0x08, 0x0e, 0x00], // 0x5b [
+    [0x00, 0x10, 0x08, 0x04, 0x02, 0x01, 0x00, 0x00], // 0x5c \
+    [0x0e, 0x02, 0x02, 0x02, 0x02, 0x02, 0x0e, 0x00], // 0x5d ]
+    [0x04, 0x0a, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x5e ^
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1f, 0x00], // 0x5f _
+    [0x08, 0x04, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00], // 0x60 `
+    [0x00, 0x00, 0x0e, 0x01, 0x0f, 0x11, 0x0f, 0x00], // 0x61 a
+    [0x10, 0x10, 0x16, 0x19, 0x11, 0x11, 0x1e, 0x00], // 0x62 b
+    [0x00, 0x00, 0x0e, 0x10, 0x10, 0x11, 0x0e, 0x00], // 0x63 c
+    [0x01, 0x01, 0x0d, 0x13, 0x11, 0x11, 0x0f, 0x00], // 0x64 d
+    [0x00, 0x00, 0x0e, 0x11, 0x1f, 0x10, 0x0e, 0x00], // 0x65 e
+    [0x06, 0x09, 0x08, 0x1c, 0x08, 0x08, 0x08, 0x00], // 0x66 f
+    [0x00, 0x0f, 0x11, 0x11, 0x0f, 0x01, 0x0e, 0x00], // 0x67 g
+    [0x10, 0x10, 0x16, 0x19, 0x11, 0x11, 0x11, 0x00], // 0x68 h
+    [0x04, 0x00, 0x0c, 0x04, 0x04, 0x04, 0x0e, 0x00], // 0x69 i
+    [0x02, 0x00, 0x06, 0x02, 0x02, 0x12, 0x0c, 0x00], // 0x6a j
+    [0x10, 0x10, 0x12, 0x14, 0x18, 0x14, 0x12, 0x00], // 0x6b k
+    [0x0c, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0e, 0x00], // 0x6c l
+    [0x00, 0x00, 0x1a, 0x15, 0x15, 0x11, 0x11, 0x00], // 0x6d m
+    [0x00, 0x00, 0x16, 0x19, 0x11, 0x11, 0x11, 0x00], // 0x6e n
+    [0x00, 0x00, 0x0e, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0x6f o
+    [0x00, 0x00, 0x1e, 0x11, 0x1e, 0x10, 0x10, 0x00], // 0x70 p
+    [0x00, 0x00, 0x0d, 0x13, 0x0f, 0x01, 0x01, 0x00], // 0x71 q
+    [0x00, 0x00, 0x16, 0x19, 0x10, 0x10, 0x10, 0x00], // 0x72 r
+    [0x00, 0x00, 0x0e, 0x10, 0x0e, 0x01, 0x1e, 0x00], // 0x73 s
+    [0x08, 0x08, 0x1c, 0x08, 0x08, 0x09, 0x06, 0x00], // 0x74 t
+    [0x00, 0x00, 0x11, 0x11, 0x11, 0x13, 0x0d, 0x00], // 0x75 u
+    [0x00, 0x00, 0x11, 0x11, 0x11, 0x0a, 0x04, 0x00], // 0x76 v
+    [0x00, 0x00, 0x11, 0x11, 0x15, 0x15, 0x0a, 0x00], // 0x77 w
+    [0x00, 0x00, 0x11, 0x0a, 0x04, 0x0a, 0x11, 0x00], // 0x78 x
+    [0x00, 0x00, 0x11, 0x11, 0x0f, 0x01, 0x0e, 0x00], // 0x79 y
+    [0x00, 0x00, 0x1f, 0x02, 0x04, 0x08, 0x1f, 0x00], // 0x7a z
+    [0x02, 0x04, 0x04, 0x08, 0x04, 0x04, 0x02, 0x00], // 0x7b {
+    [0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x00], // 0x7c |
+    [0x08, 0x04, 0x04, 0x02, 0x04, 0x04, 0x08, 0x00], // 0x7d }
+    [0x00, 0x00, 0x08, 0x15, 0x02, 0x00, 0x00, 0x00], // 0x7e ~
+    [0x00, 0x04, 0x0a, 0x11, 0x11, 0x11, 0x1f, 0x00], // 0x7f house
+    [0x1f, 0x10, 0x10, 0x1e, 0x11, 0x11, 0x1e, 0x00], // 0x80 cyrillic be
+    [0x06, 0x0a, 0x0a, 0x0a, 0x0a, 0x1f, 0x11, 0x00], // 0x81 cyrillic de
+    [0x15, 0x15, 0x15, 0x0e, 0x15, 0x15, 0x15, 0x00], // 0x82 cyrillic zhe
+    [0x1e, 0x01, 0x01, 0x0e, 0x01, 0x01, 0x1e, 0x00], // 0x83 cyrillic ze
+    [0x11, 0x11, 0x13, 0x15, 0x19, 0x11, 0x11, 0x00], // 0x84 cyrillic i
+    [0x0a, 0x04, 0x11, 0x13, 0x15, 0x19, 0x11, 0x00], // 0x85 cyrillic short i
+    [0x07, 0x09, 0x09, 0x09, 0x09, 0x09, 0x11, 0x00], // 0x86 cyrillic el
+    [0x1f, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x00], // 0x87 cyrillic pe
+    [0x11, 0x11, 0x11, 0x0f, 0x01, 0x01, 0x0e, 0x00], // 0x88 cyrillic u
+    [0x12, 0x12, 0x12, 0x12, 0x12, 0x1f, 0x01, 0x00], // 0x89 cyrillic tse
+    [0x11, 0x11, 0x11, 0x0f, 0x01, 0x01, 0x01, 0x00], // 0x8a cyrillic che
+    [0x15, 0x15, 0x15, 0x15, 0x15, 0x15, 0x1f, 0x00], // 0x8b cyrillic sha
+    [0x15, 0x15, 0x15, 0x15, 0x15, 0x1f, 0x01, 0x00], // 0x8c cyrillic shcha
+    [0x18, 0x08, 0x08, 0x0e, 0x09, 0x09, 0x0e, 0x00], // 0x8d cyrillic hard sign
+    [0x11, 0x11, 0x11, 0x19, 0x15, 0x15, 0x19, 0x00], // 0x8e cyrillic yeru
+    [0x0e, 0x11, 0x01, 0x07, 0x01, 0x11, 0x0e, 0x00], // 0x8f cyrillic e
+    [0x00, 0x00, 0x09, 0x15, 0x12, 0x12, 0x0d, 0x00], // 0x90 greek small alpha
+    [0x04, 0x06, 0x05, 0x04, 0x04, 0x1c, 0x1c, 0x00], // 0x91 eighth note
+    [0x1f, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x00], // 0x92 greek capital gamma
+    [0x00, 0x00, 0x1f, 0x0a, 0x0a, 0x0a, 0x09, 0x00], // 0x93 greek small pi
+    [0x1f, 0x10, 0x08, 0x04, 0x08, 0x10, 0x1f, 0x00], // 0x94 greek capital sigma
+    [0x00, 0x00, 0x0f, 0x14, 0x11, 0x11, 0x0e, 0x00], // 0x95 greek small sigma
+    [0x0f, 0x09, 0x0f, 0x09, 0x09, 0x1b, 0x1b, 0x00], // 0x96 beamed eighth notes
+    [0x00, 0x00, 0x1f, 0x04, 0x04, 0x04, 0x02, 0x00], // 0x97 greek small tau
+    [0x04, 0x0e, 0x0e, 0x0e, 0x1f, 0x04, 0x00, 0x00], // 0x98 bell
+    [0x0e, 0x11, 0x11, 0x1f, 0x11, 0x11, 0x0e, 0x00], // 0x99 greek capital theta
+    [0x0e, 0x11, 0x11, 0x11, 0x0a, 0x0a, 0x1b, 0x00], // 0x9a greek capital omega
+    [0x0c, 0x10, 0x08, 0x0e, 0x11, 0x11, 0x0e, 0x00], // 0x9b greek small delta
+    [0x00, 0x00, 0x0a, 0x15, 0x15, 0x0a, 0x00, 0x00], // 0x9c infinity
+    [0x00, 0x0a, 0x1f, 0x1f, 0x0e, 0x04, 0x00, 0x00], // 0x9d black heart
+    [0x00, 0x00, 0x0e, 0x10, 0x0c, 0x10, 0x0e, 0x00], // 0x9e greek small epsilon
+    [0x00, 0x0e, 0x11, 0x11, 0x11, 0x11, 0x00, 0x00], // 0x9f intersection
+    [0x0a, 0x0a, 0x0a, 0x0a, 0x0a, 0x0a, 0x0a, 0x00], // 0xa0 double vertical bar
+    [0x04, 0x00, 0x04, 0x04, 0x04, 0x04, 0x04, 0x00], // 0xa1 inverted exclamation mark
+    [0x04, 0x0e, 0x14, 0x14, 0x15, 0x0e, 0x04, 0x00], // 0xa2 cent sign
+    [0x06, 0x09, 0x08, 0x1c, 0x08, 0x09, 0x16, 0x00], // 0xa3 pound sign
+    [0x00, 0x11, 0x0e, 0x0a, 0x0e, 0x11, 0x00, 0x00], // 0xa4 currency sign
+    [0x11, 0x0a, 0x1f, 0x04, 0x1f, 0x04, 0x04, 0x00], // 0xa5 yen sign
+    [0x04, 0x04, 0x04, 0x00, 0x04, 0x04, 0x04, 0x00], // 0xa6 broken bar
+    [0x0e, 0x10, 0x0e, 0x11, 0x0e, 0x01, 0x0e, 0x00], // 0xa7 section sign
+    [0x02, 0x05, 0x04, 0x0e, 0x04, 0x04, 0x14, 0x00], // 0xa8 f with hook
+    [0x0e, 0x11, 0x1b, 0x19, 0x1b, 0x11, 0x0e, 0x00], // 0xa9 copyright sign
+    [0x0e, 0x01, 0x0f, 0x11, 0x0f, 0x00, 0x1f, 0x00], // 0xaa feminine ordinal indicator
+    [0x00, 0x05, 0x0a, 0x14, 0x0a, 0x05, 0x00, 0x00], // 0xab left double angle quotation mark
+    [0x12, 0x15, 0x15, 0x1d, 0x15, 0x15, 0x12, 0x00], // 0xac cyrillic yu
+    [0x0f, 0x11, 0x11, 0x0f, 0x05, 0x09, 0x11, 0x00], // 0xad cyrillic ya
+    [0x0e, 0x11, 0x1d, 0x19, 0x15, 0x11, 0x0e, 0x00], // 0xae registered sign
+    [0x04, 0x08, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00], // 0xaf left single quotation mark
+    [0x0c, 0x12, 0x12, 0x0c, 0x00, 0x00, 0x00, 0x00], // 0xb0 degree sign
+    [0x04, 0x04, 0x1f, 0x04, 0x04, 0x00, 0x1f, 0x00], // 0xb1 plus-minus sign
+    [0x0c, 0x02, 0x04, 0x08, 0x0e, 0x00, 0x00, 0x00], // 0xb2 superscript two
+    [0x0c, 0x02, 0x04, 0x02, 0x0c, 0x00, 0x00, 0x00], // 0xb3 superscript three
+    [0x1c, 0x14, 0x1c, 0x17, 0x12, 0x12, 0x11, 0x00], // 0xb4 peseta sign
+    [0x00, 0x11, 0x11, 0x11, 0x13, 0x1d, 0x10, 0x00], // 0xb5 micro sign
+    [0x0f, 0x1d, 0x1d, 0x0d, 0x05, 0x05, 0x05, 0x00], // 0xb6 pilcrow sign
+    [0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00], // 0xb7 middle dot
+    [0x00, 0x00, 0x0a, 0x11, 0x15, 0x15, 0x0a, 0x00], // 0xb8 greek small omega
+    [0x08, 0x18, 0x08, 0x08, 0x1c, 0x00, 0x00, 0x00], // 0xb9 superscript one
+    [0x0e, 0x11, 0x11, 0x11, 0x0e, 0x00, 0x1f, 0x00], // 0xba masculine ordinal indicator
+    [0x00, 0x14, 0x0a, 0x05, 0x0a, 0x14, 0x00, 0x00], // 0xbb right double angle quotation mark
+    [0x10, 0x12, 0x14, 0x0a, 0x16, 0x07, 0x02, 0x00], // 0xbc one quarter
+    [0x10, 0x12, 0x14, 0x0f, 0x11, 0x02, 0x07, 0x00], // 0xbd one half
+    [0x18, 0x0a, 0x1c, 0x0a, 0x1a, 0x07, 0x02, 0x00], // 0xbe three quarters
+    [0x04, 0x00, 0x04, 0x08, 0x10, 0x11, 0x0e, 0x00], // 0xbf inverted question mark
+    [0x08, 0x04, 0x0e, 0x11, 0x1f, 0x11, 0x11, 0x00], // 0xc0 A grave
+    [0x02, 0x04, 0x0e, 0x11, 0x1f, 0x11, 0x11, 0x00], // 0xc1 A acute
+    [0x04, 0x0a, 0x0e, 0x11, 0x1f, 0x11, 0x11, 0x00], // 0xc2 A circumflex
+    [0x0a, 0x14, 0x0e, 0x11, 0x1f, 0x11, 0x11, 0x00], // 0xc3 A tilde
+    [0x11, 0x0e, 0x11, 0x11, 0x1f, 0x11, 0x11, 0x00], // 0xc4 A diaeresis
+    [0x04, 0x0a, 0x04, 0x0e, 0x11, 0x1f, 0x11, 0x00], // 0xc5 A ring above
+    [0x0f, 0x14, 0x14, 0x1e, 0x14, 0x14, 0x17, 0x00], // 0xc6 AE
+    [0x0e, 0x11, 0x10, 0x11, 0x0e, 0x04, 0x0c, 0x00], // 0xc7 C cedilla
+    [0x08, 0x04, 0x1f, 0x10, 0x1e, 0x10, 0x1f, 0x00], // 0xc8 E grave
+    [0x02, 0x04, 0x1f, 0x10, 0x1e, 0x10, 0x1f, 0x00], // 0xc9 E acute
+    [0x04, 0x0a, 0x1f, 0x10, 0x1e, 0x10, 0x1f, 0x00], // 0xca E circumflex
+    [0x0a, 0x00, 0x1f, 0x10, 0x1e, 0x10, 0x1f, 0x00], // 0xcb E diaeresis
+    [0x08, 0x04, 0x0e, 0x04, 0x04, 0x04, 0x0e, 0x00], // 0xcc I grave
+    [0x02, 0x04, 0x0e, 0x04, 0x04, 0x04, 0x0e, 0x00], // 0xcd I acute
+    [0x04, 0x0a, 0x0e, 0x04, 0x04, 0x04, 0x0e, 0x00], // 0xce I circumflex
+    [0x0a, 0x00, 0x0e, 0x04, 0x04, 0x04, 0x0e, 0x00], // 0xcf I diaeresis
+    [0x1c, 0x12, 0x11, 0x1d, 0x11, 0x12, 0x1c, 0x00], // 0xd0 Eth
+    [0x0a, 0x14, 0x11, 0x19, 0x15, 0x13, 0x11, 0x00], // 0xd1 N tilde
+    [0x08, 0x04, 0x0e, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0xd2 O grave
+    [0x02, 0x04, 0x0e, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0xd3 O acute
+    [0x04, 0x0a, 0x0e, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0xd4 O circumflex
+    [0x0a, 0x14, 0x0e, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0xd5 O tilde
+    [0x11, 0x0e, 0x11, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0xd6 O diaeresis
+    [0x00, 0x11, 0x0a, 0x04, 0x0a, 0x11, 0x00, 0x00], // 0xd7 multiplication sign
+    [0x0e, 0x13, 0x15, 0x15, 0x15, 0x19, 0x0e, 0x00], // 0xd8 O stroke
+    [0x08, 0x04, 0x11, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0xd9 U grave
+    [0x02, 0x04, 0x11, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0xda U acute
+    [0x04, 0x0a, 0x11, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0xdb U circumflex
+    [0x11, 0x00, 0x11, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0xdc U diaeresis
+    [0x02, 0x04, 0x11, 0x0a, 0x04, 0x04, 0x04, 0x00], // 0xdd Y acute
+    [0x10, 0x1e, 0x11, 0x11, 0x1e, 0x10, 0x10, 0x00], // 0xde Thorn
+    [0x0c, 0x12, 0x12, 0x16, 0x11, 0x11, 0x16, 0x00], // 0xdf sharp s
+    [0x08, 0x04, 0x0e, 0x01, 0x0f, 0x11, 0x0f, 0x00], // 0xe0 a grave
+    [0x02, 0x04, 0x0e, 0x01, 0x0f, 0x11, 0x0f, 0x00], // 0xe1 a acute
+    [0x04, 0x0a, 0x0e, 0x01, 0x0f, 0x11, 0x0f, 0x00], // 0xe2 a circumflex
+    [0x0a, 0x14, 0x0e, 0x01, 0x0f, 0x11, 0x0f, 0x00], // 0xe3 a tilde
+    [0x0a, 0x00, 0x0e, 0x01, 0x0f, 0x11, 0x0f, 0x00], // 0xe4 a diaeresis
+    [0x04, 0x0a, 0x0e, 0x01, 0x0f, 0x11, 0x0f, 0x00], // 0xe5 a ring above
+    [0x00, 0x00, 0x1a, 0x05, 0x0f, 0x14, 0x0f, 0x00], // 0xe6 ae
+    [0x00, 0x0e, 0x10, 0x11, 0x0e, 0x04, 0x0c, 0x00], // 0xe7 c cedilla
+    [0x08, 0x04, 0x0e, 0x11, 0x1f, 0x10, 0x0e, 0x00], // 0xe8 e grave
+    [0x02, 0x04, 0x0e, 0x11, 0x1f, 0x10, 0x0e, 0x00], // 0xe9 e acute
+    [0x04, 0x0a, 0x0e, 0x11, 0x1f, 0x10, 0x0e, 0x00], // 0xea e circumflex
+    [0x0a, 0x00, 0x0e, 0x11, 0x1f, 0x10, 0x0e, 0x00], // 0xeb e diaeresis
+    [0x08, 0x04, 0x0c, 0x04, 0x04, 0x04, 0x0e, 0x00], // 0xec i grave
+    [0x02, 0x04, 0x0c, 0x04, 0x04, 0x04, 0x0e, 0x00], // 0xed i acute
+    [0x04, 0x0a, 0x0c, 0x04, 0x04, 0x04, 0x0e, 0x00], // 0xee i circumflex
+    [0x0a, 0x00, 0x0c, 0x04, 0x04, 0x04, 0x0e, 0x00], // 0xef i diaeresis
+    [0x0a, 0x04, 0x0a, 0x01, 0x0f, 0x11, 0x0e, 0x00], // 0xf0 eth
+    [0x0a, 0x14, 0x16, 0x19, 0x11, 0x11, 0x11, 0x00], // 0xf1 n tilde
+    [0x08, 0x04, 0x0e, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0xf2 o grave
+    [0x02, 0x04, 0x0e, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0xf3 o acute
+    [0x04, 0x0a, 0x0e, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0xf4 o circumflex
+    [0x0a, 0x14, 0x0e, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0xf5 o tilde
+    [0x0a, 0x00, 0x0e, 0x11, 0x11, 0x11, 0x0e, 0x00], // 0xf6 o diaeresis
+    [0x00, 0x04, 0x00, 0x1f, 0x00, 0x04, 0x00, 0x00], // 0xf7 division sign
+    [0x00, 0x00, 0x0e, 0x13, 0x15, 0x19, 0x0e, 0x00], // 0xf8 o stroke
+    [0x08, 0x04, 0x11, 0x11, 0x11, 0x13, 0x0d, 0x00], // 0xf9 u grave
+    [0x02, 0x04, 0x11, 0x11, 0x11, 0x13, 0x0d, 0x00], // 0xfa u acute
+    [0x04, 0x0a, 0x11, 0x11, 0x11, 0x13, 0x0d, 0x00], // 0xfb u circumflex
+    [0x0a, 0x00, 0x11, 0x11, 0x11, 0x13, 0x0d, 0x00], // 0xfc u diaeresis
+    [0x02, 0x04, 0x11, 0x11, 0x0f, 0x01, 0x0e, 0x00], // 0xfd y acute
+    [0x00, 0x10, 0x1e, 0x11, 0x1e, 0x10, 0x10, 0x00], // 0xfe thorn
+    [0x0a, 0x00, 0x11, 0x11, 0x0f, 0x01, 0x0e, 0x00], // 0xff y diaeresis
+];
+
+const A00_5X10: [[u8; 10]; 32] = [
+    [0x00, 0x00, 0x09, 0x15, 0x12, 0x12, 0x0d, 0x00, 0x00, 0x00], // 0xe0 greek small alpha
+    [0x0a, 0x00, 0x0e, 0x01, 0x0f, 0x11, 0x0f, 0x00, 0x00, 0x00], // 0xe1 a diaeresis
+    [0x00, 0x00, 0x0e, 0x11, 0x1e, 0x11, 0x1e, 0x10, 0x10, 0x10], // 0xe2 greek small beta
+    [0x00, 0x00, 0x0e, 0x10, 0x0c, 0x11, 0x0e, 0x00, 0x00, 0x00], // 0xe3 greek small epsilon
+    [0x00, 0x00, 0x11, 0x11, 0x11, 0x13, 0x1d, 0x10, 0x10, 0x10], // 0xe4 greek small mu
+    [0x00, 0x00, 0x0f, 0x14, 0x11, 0x11, 0x0e, 0x00, 0x00, 0x00], // 0xe5 greek small sigma
+    [0x00, 0x00, 0x06, 0x09, 0x11, 0x11, 0x1e, 0x10, 0x10, 0x10], // 0xe6 greek small rho
+    [0x00, 0x00, 0x0f, 0x11, 0x11, 0x11, 0x0f, 0x01, 0x01, 0x0e], // 0xe7 g with descender
+    [0x00, 0x00, 0x07, 0x04, 0x04, 0x14, 0x08, 0x00, 0x00, 0x00], // 0xe8 square root
+    [0x00, 0x02, 0x1b, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0xe9 superscript minus one
+    [0x02, 0x00, 0x06, 0x02, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0c], // 0xea j with descender
+    [0x00, 0x14, 0x08, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0xeb superscript x
+    [0x00, 0x04, 0x0e, 0x14, 0x15, 0x0e, 0x04, 0x00, 0x00, 0x00], // 0xec cent sign
+    [0x08, 0x08, 0x1c, 0x08, 0x1c, 0x08, 0x0f, 0x00, 0x00, 0x00], // 0xed pound sign
+    [0x0e, 0x00, 0x16, 0x19, 0x11, 0x11, 0x11, 0x00, 0x00, 0x00], // 0xee n with line above
+    [0x0a, 0x00, 0x0e, 0x11, 0x11, 0x11, 0x0e, 0x00, 0x00, 0x00], // 0xef o diaeresis
+    [0x00, 0x00, 0x16, 0x19, 0x11, 0x11, 0x1e, 0x10, 0x10, 0x10], // 0xf0 p with descender
+    [0x00, 0x00, 0x0d, 0x13, 0x11, 0x11, 0x0f, 0x01, 0x01, 0x01], // 0xf1 q with descender
+    [0x00, 0x0e, 0x11, 0x1f, 0x11, 0x11, 0x0e, 0x00, 0x00, 0x00], // 0xf2 greek small theta
+    [0x00, 0x00, 0x00, 0x0a, 0x15, 0x0a, 0x00, 0x00, 0x00, 0x00], // 0xf3 infinity
+    [0x00, 0x0e, 0x11, 0x11, 0x11, 0x0a, 0x1b, 0x00, 0x00, 0x00], // 0xf4 greek capital omega
+    [0x0a, 0x00, 0x11, 0x11, 0x11, 0x13, 0x0d, 0x00, 0x00, 0x00], // 0xf5 u diaeresis
+    [0x1f, 0x10, 0x08, 0x04, 0x08, 0x10, 0x1f, 0x00, 0x00, 0x00], // 0xf6 greek capital sigma
+    [0x00, 0x00, 0x1f, 0x0a, 0x0a, 0x0a, 0x13, 0x00, 0x00, 0x00], // 0xf7 greek small pi
+    [0x1f, 0x00, 0x11, 0x0a, 0x04, 0x0a, 0x11, 0x00, 0x00, 0x00], // 0xf8 x bar
+    [0x00, 0x00, 0x11, 0x11, 0x11, 0x11, 0x0f, 0x01, 0x01, 0x0e], // 0xf9 y with descender
+    [0x01, 0x1e, 0x04, 0x1f, 0x04, 0x04, 0x04, 0x00, 0x00, 0x00], // 0xfa cjk thousand
+    [0x00, 0x1f, 0x08, 0x0f, 0x09, 0x11, 0x13, 0x00, 0x00, 0x00], // 0xfb cjk ten thousand
+    [0x1f, 0x15, 0x15, 0x1f, 0x11, 0x11, 0x13, 0x00, 0x00, 0x00], // 0xfc cjk yen
+    [0x00, 0x04, 0x00, 0x1f, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00], // 0xfd division sign
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // 0xfe empty
+    [0x1f, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f, 0x1f], // 0xff all black
+];
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::characters::{NonASCIIA00, NonASCIIA02};
+
+    #[test]
+    fn tables_match_the_codes() {
+        let a = [0x0e, 0x11, 0x11, 0x11, 0x1f, 0x11, 0x11, 0x00];
+        assert_eq!(Rom::A00.glyph_5x8(b'A').unwrap().rows(), a);
+        assert_eq!(Rom::A02.glyph_5x8(b'A').unwrap().rows(), a);
+        // the small circles printed for '°'
+        let degree = NonASCIIA00::KatakanaHiraganaSemiVoicedSoundMark as u8;
+        assert_eq!((degree, Rom::A00.glyph_5x8(degree).unwrap().rows()), (0xdf, [0x1c, 0x14, 0x1c, 0, 0, 0, 0, 0]));
+        let degree = NonASCIIA02::DegreeSign as u8;
+        assert_eq!((degree, Rom::A02.glyph_5x8(degree).unwrap().rows()), (0xb0, [0x0c, 0x12, 0x12, 0x0c, 0, 0, 0, 0]));
+    }
+
+    #[test]
+    fn only_a00_has_tall_glyphs_from_0xe0_on() {
+        let g = NonASCIIA00::LatinSmallGDecender as u8;
+        assert_eq!(Rom::A00.glyph_5x10(g).unwrap().rows()[7..], [0x01, 0x01, 0x0e]);
+        assert_eq!(Rom::A00.glyph_5x8(g).unwrap().rows()[5..], [0x0f, 0x01, 0x0e]);
+        // other glyphs are the 5x8 ones with two empty rows below
+        for (rom, code) in [(Rom::A00, 0xdf), (Rom::A02, 0xe7), (Rom::A02, 0xff)] {
+            let rows = rom.glyph_5x10(code).unwrap().rows();
+            assert_eq!(rows[..8], rom.glyph_5x8(code).unwrap().rows());
+            assert_eq!(rows[8..], [0, 0]);
+        }
+    }
+
+    #[test]
+    fn custom_character_codes_have_no_glyph() {
+        for rom in [Rom::A00, Rom::A02] {
+            assert_eq!(GlyphSource::<8>::glyph(&rom, 0x0f), None);
+            assert_eq!(GlyphSource::<10>::glyph(&rom, 0x00), None);
+            assert!(GlyphSource::<8>::glyph(&rom, 0x10).is_some());
+        }
+    }
+}
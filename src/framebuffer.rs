@@ -0,0 +1,302 @@
+
+use core::fmt;
+
+use crate::{
+    FnsetFont, Hd44780, Hd44780Error,
+    characters::Rom,
+    interface::InterfaceTrait,
+    types::DisplayTypeTrait,
+};
+
+#[cfg(feature="async")]
+use crate::{Hd44780Async, interface::AsyncInterfaceTrait};
+
+
+/// A single HD44780 controller addresses at most 80 characters (e.g. 4x20 or 2x40).
+pub const MAX_CELLS: usize = 80;
+
+// Unchanged cells between two changed ones are rewritten instead of jumping with SetDd,
+// as long as this costs no more bytes on the bus than the jump itself.
+const MAX_BRIDGE: usize = 1;
+
+
+/// In-RAM shadow of the display contents.
+/// Draw into the buffer as often as needed and call `flush` to send only the cells that
+/// changed since the last flush. The display has to be in `EntryDir::Inc` mode.
+/// Drawing outside of the display is clipped.
+pub struct FrameBuffer {
+    rows: u8,
+    cols: u8,
+    cells: [u8; MAX_CELLS],
+    shown: [u8; MAX_CELLS],
+    synced: bool,
+    cursor: (u8, u8),
+    rom: Option<Rom>,
+    replacement: u8,
+    font: FnsetFont,
+}
+
+impl FrameBuffer {
+    /// Creates a blank buffer sized for `dp_type` (see [`Hd44780::display_type`]),
+    /// rows which exceed [`MAX_CELLS`] are dropped.
+    pub fn new<DPTYPE: DisplayTypeTrait>(dp_type: &DPTYPE) -> Self {
+        let cols = dp_type.cols().min(MAX_CELLS as u8);
+        let rows = (MAX_CELLS as u8).checked_div(cols).map_or(0, |max| dp_type.rows().min(max));
+        Self {
+            rows,
+            cols,
+            cells: [b' '; MAX_CELLS],
+            shown: [b' '; MAX_CELLS],
+            synced: false,
+            cursor: (0, 0),
+            rom: None,
+            replacement: b'?',
+            font: dp_type.font(),
+        }
+    }
+
+    /// Select the character ROM strings are encoded for, as with [`Hd44780::character_rom`].
+    /// `None` (default) writes strings as raw bytes.
+    pub fn character_rom(&mut self, rom:Option<Rom>) -> &mut Self {
+        self.rom = rom;
+        self
+    }
+
+    /// Character code written instead of chars missing in the selected ROM, defaults to `'?'`.
+    pub fn replacement_char(&mut self, code:u8) -> &mut Self {
+        self.replacement = code;
+        self
+    }
+
+    // character codes of `c`, its UTF-8 bytes without a ROM
+    fn encode<'a>(&self, c:char, buf:&'a mut [u8; 4]) -> &'a [u8] {
+        match self.rom {
+            Some(rom) => {
+                buf[0] = rom.encode_for(c, self.font).unwrap_or(self.replacement);
+                &buf[..1]
+            },
+            None => c.encode_utf8(buf).as_bytes(),
+        }
+    }
+
+    pub fn rows(&self) -> u8 {
+        self.rows
+    }
+
+    pub fn cols(&self) -> u8 {
+        self.cols
+    }
+
+    fn index(&self, row:u8, col:u8) -> Option<usize> {
+        if row < self.rows && col < self.cols {
+            Some(row as usize * self.cols as usize + col as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Fills the buffer with spaces.
+    pub fn clear(&mut self) -> &mut Self {
+        self.cells = [b' '; MAX_CELLS];
+        self.cursor = (0, 0);
+        self
+    }
+
+    /// Forgets what is on the display, the next flush redraws every cell.
+    pub fn invalidate(&mut self) -> &mut Self {
+        self.synced = false;
+        self
+    }
+
+    pub fn get(&self, row:u8, col:u8) -> Option<u8> {
+        self.index(row, col).map(|i| self.cells[i])
+    }
+
+    /// Sets a single cell to the character code `byte`.
+    pub fn set(&mut self, row:u8, col:u8, byte:u8) -> &mut Self {
+        if let Some(i) = self.index(row, col) {
+            self.cells[i] = byte;
+        }
+        self
+    }
+
+    /// Writes raw character codes starting at `row`/`col`, clipped at the end of the row.
+    pub fn write_bytes(&mut self, row:u8, col:u8, bytes:&[u8]) -> &mut Self {
+        for (i, &byte) in bytes.iter().take(self.cols as usize).enumerate() {
+            match col.checked_add(i as u8) {
+                Some(c) if c < self.cols => { self.set(row, c, byte); },
+                _ => break,
+            }
+        }
+        self
+    }
+
+    /// Writes `string` encoded for the selected ROM starting at `row`/`col`, clipped at the end of the row.
+    pub fn write_string(&mut self, row:u8, col:u8, string:&str) -> &mut Self {
+        let mut col = col;
+        for c in string.chars() {
+            let mut buf = [0; 4];
+            for &byte in self.encode(c, &mut buf) {
+                if col >= self.cols {
+                    return self;
+                }
+                self.set(row, col, byte);
+                col += 1;
+            }
+        }
+        self
+    }
+
+    /// Moves the cursor used by the `fmt::Write` implementation.
+    pub fn set_cursor(&mut self, row:u8, col:u8) -> &mut Self {
+        self.cursor = (row, col);
+        self
+    }
+
+    /// Sends every changed cell to the display. Runs of changed cells are written sequentially,
+    /// the cursor is only repositioned where this saves bus traffic.
+    pub fn flush<INTERFACE, DPTYPE>(&mut self, dp: &mut Hd44780<INTERFACE, DPTYPE>) -> Result<(), Hd44780Error<INTERFACE::Error>>
+    where
+        INTERFACE: InterfaceTrait,
+        DPTYPE: DisplayTypeTrait,
+    {
+        for row in 0..self.rows {
+            let base = row as usize * self.cols as usize;
+            let cells = &self.cells[base..base + self.cols as usize];
+            let shown = &self.shown[base..base + self.cols as usize];
+            flush_row(dp, row, 0, cells, |col| !self.synced || cells[col] != shown[col])?;
+        }
+        self.shown = self.cells;
+        self.synced = true;
+        Ok(())
+    }
+
+    /// Async counterpart of [`FrameBuffer::flush`].
+    #[cfg(feature="async")]
+    pub async fn flush_async<INTERFACE, DPTYPE>(&mut self, dp: &mut Hd44780Async<INTERFACE, DPTYPE>) -> Result<(), Hd44780Error<INTERFACE::Error>>
+    where
+        INTERFACE: AsyncInterfaceTrait,
+        DPTYPE: DisplayTypeTrait,
+    {
+        for row in 0..self.rows {
+            let base = row as usize * self.cols as usize;
+            let cells = &self.cells[base..base + self.cols as usize];
+            let shown = &self.shown[base..base + self.cols as usize];
+            flush_row_async(dp, row, 0, cells, |col| !self.synced || cells[col] != shown[col]).await?;
+        }
+        self.shown = self.cells;
+        self.synced = true;
+        Ok(())
+    }
+}
+
+// Finds the next run of `len` cells starting at or after `from` which has to be sent,
+// returns its start and (exclusive) end.
+fn next_run(len:usize, from:usize, changed:&impl Fn(usize) -> bool) -> Option<(usize, usize)> {
+    let start = (from..len).find(|&i| changed(i))?;
+    let mut end = start + 1;
+    while let Some(next) = (end..len).find(|&i| changed(i)) {
+        if next - end > MAX_BRIDGE {
+            break;
+        }
+        end = next + 1;
+    }
+    Some((start, end))
+}
+
+// Sends the cells of `codes` for which `changed` holds, `codes` is shown from `row`/`col` on.
+// Shared by the frame buffer and the widgets which remember what they have drawn.
+pub(crate) fn flush_row<INTERFACE, DPTYPE>(dp: &mut Hd44780<INTERFACE, DPTYPE>, row:u8, col:u8, codes:&[u8], changed:impl Fn(usize) -> bool) -> Result<(), Hd44780Error<INTERFACE::Error>>
+where
+    INTERFACE: InterfaceTrait,
+    DPTYPE: DisplayTypeTrait,
+{
+    let mut from = 0;
+    while let Some((start, end)) = next_run(codes.len(), from, &changed) {
+        // runs are separated by more unchanged cells than a jump costs
+        dp.position(row, col + start as u8)?;
+        dp.print_bytes(&codes[start..end])?;
+        from = end;
+    }
+    Ok(())
+}
+
+#[cfg(feature="async")]
+pub(crate) async fn flush_row_async<INTERFACE, DPTYPE>(dp: &mut Hd44780Async<INTERFACE, DPTYPE>, row:u8, col:u8, codes:&[u8], changed:impl Fn(usize) -> bool) -> Result<(), Hd44780Error<INTERFACE::Error>>
+where
+    INTERFACE: AsyncInterfaceTrait,
+    DPTYPE: DisplayTypeTrait,
+{
+    let mut from = 0;
+    while let Some((start, end)) = next_run(codes.len(), from, &changed) {
+        dp.position(row, col + start as u8).await?;
+        dp.print_bytes(&codes[start..end]).await?;
+        from = end;
+    }
+    Ok(())
+}
+
+/// Writes at the cursor set by [`FrameBuffer::set_cursor`] like `write_string`, `'\n'` moves to the start of the next row.
+impl fmt::Write for FrameBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if c == '\n' {
+                self.cursor = (self.cursor.0.saturating_add(1), 0);
+                continue;
+            }
+            let mut buf = [0; 4];
+            for &byte in self.encode(c, &mut buf) {
+                let (row, col) = self.cursor;
+                self.set(row, col, byte);
+                self.cursor = (row, col.saturating_add(1));
+            }
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find_runs(changed:&[bool]) -> ([(usize, usize); MAX_CELLS], usize) {
+        let mut runs = [(0, 0); MAX_CELLS];
+        let (mut count, mut from) = (0, 0);
+        while let Some((start, end)) = next_run(changed.len(), from, &|i| changed[i]) {
+            runs[count] = (start, end);
+            count += 1;
+            from = end;
+        }
+        (runs, count)
+    }
+
+    #[test]
+    fn runs_of_changed_cells() {
+        let (runs, count) = find_runs(&[false, true, true, false, false, false, true, false]);
+        assert_eq!(runs[..count], [(1, 3), (6, 7)]);
+        let (_, count) = find_runs(&[false; 8]);
+        assert_eq!(count, 0);
+        let (runs, count) = find_runs(&[true; 8]);
+        assert_eq!(runs[..count], [(0, 8)]);
+    }
+
+    #[test]
+    fn runs_bridge_single_unchanged_cells() {
+        let (runs, count) = find_runs(&[true, false, true, false, true, false, false, true]);
+        assert_eq!(runs[..count], [(0, 5), (7, 8)]);
+        let (runs, count) = find_runs(&[false, true, false, false, true, false]);
+        assert_eq!(runs[..count], [(1, 2), (4, 5)]);
+    }
+
+    #[test]
+    fn write_string_encodes_for_the_rom() {
+        let mut fb = FrameBuffer::new(&crate::types::DisplayTypeFont5x8::new(1, 8, crate::FnsetLines::One));
+        fb.write_string(0, 0, "a\\é");
+        assert_eq!(fb.get(0, 1), Some(b'\\'));
+        assert_eq!((fb.get(0, 2), fb.get(0, 3)), (Some(0xc3), Some(0xa9)));
+        fb.character_rom(Some(Rom::A00)).write_string(0, 0, "¥\\");
+        assert_eq!(fb.get(0, 0), Some(0x5c));
+        assert_eq!(fb.get(0, 1), Some(b'?'));
+    }
+}
@@ -1 +1,1085 @@
-// TODO...
\ No newline at end of file
+
+use core::convert::Infallible;
+
+use embedded_hal::{delay::DelayNs, digital::{Error, ErrorKind, ErrorType, InputPin, OutputPin}};
+
+#[cfg(feature="async")]
+use embedded_hal_async::delay::DelayNs as AsyncDelayNs;
+
+use crate::{
+    instructions::{CmdOptions, FnsetDataLen, FnsetFont, FnsetLines},
+    interface::{InterfaceError, InterfaceTrait}
+};
+
+#[cfg(feature="async")]
+use crate::interface::AsyncInterfaceTrait;
+
+
+// Time the controller needs to execute a regular instruction (37us at 270kHz) plus some margin.
+// Without a RW pin the busy flag can not be polled, so every byte is followed by this delay.
+const EXEC_TIME_US: u32 = 50;
+
+
+/// Placeholder for an unconnected pin, e.g. when the backlight is hard wired.
+pub struct NoPin;
+
+impl ErrorType for NoPin {
+    type Error = Infallible;
+}
+
+impl OutputPin for NoPin {
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+
+/// Parallel 4-bit interface, the display is wired directly to MCU pins (RS, E, D4-D7).
+/// RW has to be tied to GND, so reading from the display is not supported (see [`GpioInterface4BitRw`]).
+pub struct GpioInterface4Bit<RS, EN, D4, D5, D6, D7, DELAY, BL = NoPin>
+where
+    RS: OutputPin,
+    EN: OutputPin,
+    D4: OutputPin,
+    D5: OutputPin,
+    D6: OutputPin,
+    D7: OutputPin,
+    BL: OutputPin,
+{
+    rs: RS,
+    en: EN,
+    d4: D4,
+    d5: D5,
+    d6: D6,
+    d7: D7,
+    delay: DELAY,
+    bl: BL,
+}
+
+impl<RS, EN, D4, D5, D6, D7, DELAY> GpioInterface4Bit<RS, EN, D4, D5, D6, D7, DELAY, NoPin>
+where
+    RS: OutputPin,
+    EN: OutputPin,
+    D4: OutputPin,
+    D5: OutputPin,
+    D6: OutputPin,
+    D7: OutputPin,
+{
+    pub fn new(rs: RS, en: EN, d4: D4, d5: D5, d6: D6, d7: D7, delay: DELAY) -> Self {
+        Self {
+            rs,
+            en,
+            d4,
+            d5,
+            d6,
+            d7,
+            delay,
+            bl: NoPin,
+        }
+    }
+}
+
+impl<RS, EN, D4, D5, D6, D7, DELAY, BL> GpioInterface4Bit<RS, EN, D4, D5, D6, D7, DELAY, BL>
+where
+    RS: OutputPin,
+    EN: OutputPin,
+    D4: OutputPin,
+    D5: OutputPin,
+    D6: OutputPin,
+    D7: OutputPin,
+    BL: OutputPin,
+{
+    /// Use `bl` to switch the backlight (high = on).
+    pub fn with_backlight<PIN: OutputPin>(self, bl: PIN) -> GpioInterface4Bit<RS, EN, D4, D5, D6, D7, DELAY, PIN> {
+        GpioInterface4Bit {
+            rs: self.rs,
+            en: self.en,
+            d4: self.d4,
+            d5: self.d5,
+            d6: self.d6,
+            d7: self.d7,
+            delay: self.delay,
+            bl,
+        }
+    }
+
+    fn set_rs(&mut self, rs: bool) -> Result<(), InterfaceError<ErrorKind>> {
+        self.rs.set_state(rs.into()).map_err(|e| InterfaceError::Bus(e.kind()))
+    }
+
+    // puts the lower nibble of `nibble` on D4-D7
+    fn set_nibble(&mut self, nibble: u8) -> Result<(), InterfaceError<ErrorKind>> {
+        self.d4.set_state((nibble & 0x01 != 0).into()).map_err(|e| InterfaceError::Bus(e.kind()))?;
+        self.d5.set_state((nibble & 0x02 != 0).into()).map_err(|e| InterfaceError::Bus(e.kind()))?;
+        self.d6.set_state((nibble & 0x04 != 0).into()).map_err(|e| InterfaceError::Bus(e.kind()))?;
+        self.d7.set_state((nibble & 0x08 != 0).into()).map_err(|e| InterfaceError::Bus(e.kind()))?;
+        Ok(())
+    }
+
+    fn set_en(&mut self, en: bool) -> Result<(), InterfaceError<ErrorKind>> {
+        self.en.set_state(en.into()).map_err(|e| InterfaceError::Bus(e.kind()))
+    }
+}
+
+
+impl<RS, EN, D4, D5, D6, D7, DELAY, BL> GpioInterface4Bit<RS, EN, D4, D5, D6, D7, DELAY, BL>
+where
+    RS: OutputPin,
+    EN: OutputPin,
+    D4: OutputPin,
+    D5: OutputPin,
+    D6: OutputPin,
+    D7: OutputPin,
+    DELAY: DelayNs,
+    BL: OutputPin,
+{
+    // latches the lower nibble of `nibble` with a pulse on E
+    fn write_nibble(&mut self, nibble: u8) -> Result<(), InterfaceError<ErrorKind>> {
+        self.set_nibble(nibble)?;
+        self.set_en(true)?;
+        self.delay.delay_us(1);
+        self.set_en(false)?;
+        self.delay.delay_us(1);
+        Ok(())
+    }
+}
+
+impl<RS, EN, D4, D5, D6, D7, DELAY, BL> InterfaceTrait for GpioInterface4Bit<RS, EN, D4, D5, D6, D7, DELAY, BL>
+where
+    RS: OutputPin,
+    EN: OutputPin,
+    D4: OutputPin,
+    D5: OutputPin,
+    D6: OutputPin,
+    D7: OutputPin,
+    DELAY: DelayNs,
+    BL: OutputPin,
+{
+    type Error = ErrorKind;
+
+    fn init(&mut self, fnset_lines:FnsetLines, fnset_font:FnsetFont) -> Result<(), InterfaceError<ErrorKind>> {
+        let nibble = (CmdOptions::Fnset as u8 | FnsetDataLen::Bit8 as u8) >> 4;
+
+        // wait for the supply voltage to settle
+        self.delay.delay_us(40_000);
+        self.set_rs(false)?;
+        self.set_en(false)?;
+
+        self.write_nibble(nibble)?;
+        self.delay.delay_us(4_100);
+        self.write_nibble(nibble)?;
+        self.delay.delay_us(100);
+        self.write_nibble(nibble)?;
+        self.delay.delay_us(EXEC_TIME_US);
+
+        self.write_nibble((CmdOptions::Fnset as u8 | FnsetDataLen::Bit4 as u8) >> 4)?;
+        self.delay.delay_us(EXEC_TIME_US);
+
+        // now in 4-bit mode
+
+        self.send_byte::<false>(
+            CmdOptions::Fnset as u8 | FnsetDataLen::Bit4 as u8 | fnset_lines as u8 | fnset_font as u8
+        )
+    }
+
+    fn send_byte<const RS_VAL:bool>(&mut self, byte: u8) -> Result<(), InterfaceError<ErrorKind>> {
+        self.set_rs(RS_VAL)?;
+        self.write_nibble(byte >> 4)?;
+        self.write_nibble(byte)?;
+        self.delay.delay_us(EXEC_TIME_US);
+        Ok(())
+    }
+
+    fn receive_byte<const RS_VAL:bool>(&mut self, _byte: &mut u8) -> Result<(), InterfaceError<ErrorKind>> {
+        Err(InterfaceError::ReadUnsupported)
+    }
+
+    fn backlight(&mut self, bl:bool) -> Result<(), InterfaceError<ErrorKind>> {
+        self.bl.set_state(bl.into()).map_err(|e| InterfaceError::Bus(e.kind()))
+    }
+
+    fn delay_us(&mut self, us: u32) {
+        self.delay.delay_us(us);
+    }
+}
+
+
+#[cfg(feature="async")]
+impl<RS, EN, D4, D5, D6, D7, DELAY, BL> GpioInterface4Bit<RS, EN, D4, D5, D6, D7, DELAY, BL>
+where
+    RS: OutputPin,
+    EN: OutputPin,
+    D4: OutputPin,
+    D5: OutputPin,
+    D6: OutputPin,
+    D7: OutputPin,
+    DELAY: AsyncDelayNs,
+    BL: OutputPin,
+{
+    // latches the lower nibble of `nibble` with a pulse on E
+    async fn write_nibble_async(&mut self, nibble: u8) -> Result<(), InterfaceError<ErrorKind>> {
+        self.set_nibble(nibble)?;
+        self.set_en(true)?;
+        self.delay.delay_us(1).await;
+        self.set_en(false)?;
+        self.delay.delay_us(1).await;
+        Ok(())
+    }
+}
+
+#[cfg(feature="async")]
+impl<RS, EN, D4, D5, D6, D7, DELAY, BL> AsyncInterfaceTrait for GpioInterface4Bit<RS, EN, D4, D5, D6, D7, DELAY, BL>
+where
+    RS: OutputPin,
+    EN: OutputPin,
+    D4: OutputPin,
+    D5: OutputPin,
+    D6: OutputPin,
+    D7: OutputPin,
+    DELAY: AsyncDelayNs,
+    BL: OutputPin,
+{
+    type Error = ErrorKind;
+
+    async fn init(
+        &mut self,
+        fnset_lines:FnsetLines,
+        fnset_font:FnsetFont
+    ) -> Result<(), InterfaceError<ErrorKind>>
+    {
+        let nibble = (CmdOptions::Fnset as u8 | FnsetDataLen::Bit8 as u8) >> 4;
+
+        // wait for the supply voltage to settle
+        self.delay.delay_us(40_000).await;
+        self.set_rs(false)?;
+        self.set_en(false)?;
+
+        self.write_nibble_async(nibble).await?;
+        self.delay.delay_us(4_100).await;
+        self.write_nibble_async(nibble).await?;
+        self.delay.delay_us(100).await;
+        self.write_nibble_async(nibble).await?;
+        self.delay.delay_us(EXEC_TIME_US).await;
+
+        self.write_nibble_async((CmdOptions::Fnset as u8 | FnsetDataLen::Bit4 as u8) >> 4).await?;
+        self.delay.delay_us(EXEC_TIME_US).await;
+
+        // now in 4-bit mode
+
+        self.send_byte::<false>(
+            CmdOptions::Fnset as u8 | FnsetDataLen::Bit4 as u8 | fnset_lines as u8 | fnset_font as u8
+        ).await
+    }
+
+    async fn send_byte<const RS_VAL:bool>(
+        &mut self,
+        byte: u8
+    ) -> Result<(), InterfaceError<ErrorKind>>
+    {
+        self.set_rs(RS_VAL)?;
+        self.write_nibble_async(byte >> 4).await?;
+        self.write_nibble_async(byte).await?;
+        self.delay.delay_us(EXEC_TIME_US).await;
+        Ok(())
+    }
+
+    async fn send_bytes<const RS_VAL:bool>(
+        &mut self,
+        bytes: &[u8]
+    ) -> Result<(), InterfaceError<ErrorKind>>
+    {
+        for &byte in bytes {
+            self.send_byte::<RS_VAL>(byte).await?;
+        }
+        Ok(())
+    }
+
+    async fn receive_byte<const RS_VAL:bool>(
+        &mut self,
+        _byte: &mut u8
+    ) -> Result<(), InterfaceError<ErrorKind>>
+    {
+        Err(InterfaceError::ReadUnsupported)
+    }
+
+    async fn receive_bytes<const RS_VAL:bool>(
+        &mut self,
+        _bytes: &mut [u8]
+    ) -> Result<(), InterfaceError<ErrorKind>>
+    {
+        Err(InterfaceError::ReadUnsupported)
+    }
+
+    async fn delay_us(
+        &mut self,
+        us: u32
+    )
+    {
+        self.delay.delay_us(us).await;
+    }
+
+    async fn backlight(
+        &mut self,
+        bl:bool
+    ) -> Result<(), InterfaceError<ErrorKind>>
+    {
+        self.bl.set_state(bl.into()).map_err(|e| InterfaceError::Bus(e.kind()))
+    }
+}
+
+
+/// Parallel 8-bit interface, the display is wired directly to MCU pins (RS, E, D0-D7).
+/// A byte is transferred with a single pulse on E, halving the bus transactions of the 4-bit mode.
+/// RW has to be tied to GND, so reading from the display is not supported (see [`GpioInterface4BitRw`]).
+pub struct GpioInterface8Bit<RS, EN, D0, D1, D2, D3, D4, D5, D6, D7, DELAY, BL = NoPin>
+where
+    RS: OutputPin,
+    EN: OutputPin,
+    D0: OutputPin,
+    D1: OutputPin,
+    D2: OutputPin,
+    D3: OutputPin,
+    D4: OutputPin,
+    D5: OutputPin,
+    D6: OutputPin,
+    D7: OutputPin,
+    BL: OutputPin,
+{
+    rs: RS,
+    en: EN,
+    d0: D0,
+    d1: D1,
+    d2: D2,
+    d3: D3,
+    d4: D4,
+    d5: D5,
+    d6: D6,
+    d7: D7,
+    delay: DELAY,
+    bl: BL,
+}
+
+impl<RS, EN, D0, D1, D2, D3, D4, D5, D6, D7, DELAY> GpioInterface8Bit<RS, EN, D0, D1, D2, D3, D4, D5, D6, D7, DELAY, NoPin>
+where
+    RS: OutputPin,
+    EN: OutputPin,
+    D0: OutputPin,
+    D1: OutputPin,
+    D2: OutputPin,
+    D3: OutputPin,
+    D4: OutputPin,
+    D5: OutputPin,
+    D6: OutputPin,
+    D7: OutputPin,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        rs: RS, en: EN,
+        d0: D0, d1: D1, d2: D2, d3: D3, d4: D4, d5: D5, d6: D6, d7: D7,
+        delay: DELAY
+    ) -> Self {
+        Self {
+            rs,
+            en,
+            d0,
+            d1,
+            d2,
+            d3,
+            d4,
+            d5,
+            d6,
+            d7,
+            delay,
+            bl: NoPin,
+        }
+    }
+}
+
+impl<RS, EN, D0, D1, D2, D3, D4, D5, D6, D7, DELAY, BL> GpioInterface8Bit<RS, EN, D0, D1, D2, D3, D4, D5, D6, D7, DELAY, BL>
+where
+    RS: OutputPin,
+    EN: OutputPin,
+    D0: OutputPin,
+    D1: OutputPin,
+    D2: OutputPin,
+    D3: OutputPin,
+    D4: OutputPin,
+    D5: OutputPin,
+    D6: OutputPin,
+    D7: OutputPin,
+    BL: OutputPin,
+{
+    /// Use `bl` to switch the backlight (high = on).
+    pub fn with_backlight<PIN: OutputPin>(self, bl: PIN) -> GpioInterface8Bit<RS, EN, D0, D1, D2, D3, D4, D5, D6, D7, DELAY, PIN> {
+        GpioInterface8Bit {
+            rs: self.rs,
+            en: self.en,
+            d0: self.d0,
+            d1: self.d1,
+            d2: self.d2,
+            d3: self.d3,
+            d4: self.d4,
+            d5: self.d5,
+            d6: self.d6,
+            d7: self.d7,
+            delay: self.delay,
+            bl,
+        }
+    }
+
+    fn set_rs(&mut self, rs: bool) -> Result<(), InterfaceError<ErrorKind>> {
+        self.rs.set_state(rs.into()).map_err(|e| InterfaceError::Bus(e.kind()))
+    }
+
+    // puts `byte` on D0-D7
+    fn set_byte(&mut self, byte: u8) -> Result<(), InterfaceError<ErrorKind>> {
+        self.d0.set_state((byte & 0x01 != 0).into()).map_err(|e| InterfaceError::Bus(e.kind()))?;
+        self.d1.set_state((byte & 0x02 != 0).into()).map_err(|e| InterfaceError::Bus(e.kind()))?;
+        self.d2.set_state((byte & 0x04 != 0).into()).map_err(|e| InterfaceError::Bus(e.kind()))?;
+        self.d3.set_state((byte & 0x08 != 0).into()).map_err(|e| InterfaceError::Bus(e.kind()))?;
+        self.d4.set_state((byte & 0x10 != 0).into()).map_err(|e| InterfaceError::Bus(e.kind()))?;
+        self.d5.set_state((byte & 0x20 != 0).into()).map_err(|e| InterfaceError::Bus(e.kind()))?;
+        self.d6.set_state((byte & 0x40 != 0).into()).map_err(|e| InterfaceError::Bus(e.kind()))?;
+        self.d7.set_state((byte & 0x80 != 0).into()).map_err(|e| InterfaceError::Bus(e.kind()))?;
+        Ok(())
+    }
+
+    fn set_en(&mut self, en: bool) -> Result<(), InterfaceError<ErrorKind>> {
+        self.en.set_state(en.into()).map_err(|e| InterfaceError::Bus(e.kind()))
+    }
+}
+
+
+impl<RS, EN, D0, D1, D2, D3, D4, D5, D6, D7, DELAY, BL> GpioInterface8Bit<RS, EN, D0, D1, D2, D3, D4, D5, D6, D7, DELAY, BL>
+where
+    RS: OutputPin,
+    EN: OutputPin,
+    D0: OutputPin,
+    D1: OutputPin,
+    D2: OutputPin,
+    D3: OutputPin,
+    D4: OutputPin,
+    D5: OutputPin,
+    D6: OutputPin,
+    D7: OutputPin,
+    DELAY: DelayNs,
+    BL: OutputPin,
+{
+    // latches `byte` with a pulse on E
+    fn write_byte(&mut self, byte: u8) -> Result<(), InterfaceError<ErrorKind>> {
+        self.set_byte(byte)?;
+        self.set_en(true)?;
+        self.delay.delay_us(1);
+        self.set_en(false)?;
+        self.delay.delay_us(1);
+        Ok(())
+    }
+}
+
+impl<RS, EN, D0, D1, D2, D3, D4, D5, D6, D7, DELAY, BL> InterfaceTrait for GpioInterface8Bit<RS, EN, D0, D1, D2, D3, D4, D5, D6, D7, DELAY, BL>
+where
+    RS: OutputPin,
+    EN: OutputPin,
+    D0: OutputPin,
+    D1: OutputPin,
+    D2: OutputPin,
+    D3: OutputPin,
+    D4: OutputPin,
+    D5: OutputPin,
+    D6: OutputPin,
+    D7: OutputPin,
+    DELAY: DelayNs,
+    BL: OutputPin,
+{
+    type Error = ErrorKind;
+
+    fn init(&mut self, fnset_lines:FnsetLines, fnset_font:FnsetFont) -> Result<(), InterfaceError<ErrorKind>> {
+        let byte = CmdOptions::Fnset as u8 | FnsetDataLen::Bit8 as u8;
+
+        // wait for the supply voltage to settle
+        self.delay.delay_us(40_000);
+        self.set_rs(false)?;
+        self.set_en(false)?;
+
+        self.write_byte(byte)?;
+        self.delay.delay_us(4_100);
+        self.write_byte(byte)?;
+        self.delay.delay_us(100);
+        self.write_byte(byte)?;
+        self.delay.delay_us(EXEC_TIME_US);
+
+        self.send_byte::<false>(
+            CmdOptions::Fnset as u8 | FnsetDataLen::Bit8 as u8 | fnset_lines as u8 | fnset_font as u8
+        )
+    }
+
+    fn send_byte<const RS_VAL:bool>(&mut self, byte: u8) -> Result<(), InterfaceError<ErrorKind>> {
+        self.set_rs(RS_VAL)?;
+        self.write_byte(byte)?;
+        self.delay.delay_us(EXEC_TIME_US);
+        Ok(())
+    }
+
+    fn receive_byte<const RS_VAL:bool>(&mut self, _byte: &mut u8) -> Result<(), InterfaceError<ErrorKind>> {
+        Err(InterfaceError::ReadUnsupported)
+    }
+
+    fn backlight(&mut self, bl:bool) -> Result<(), InterfaceError<ErrorKind>> {
+        self.bl.set_state(bl.into()).map_err(|e| InterfaceError::Bus(e.kind()))
+    }
+
+    fn delay_us(&mut self, us: u32) {
+        self.delay.delay_us(us);
+    }
+}
+
+
+#[cfg(feature="async")]
+impl<RS, EN, D0, D1, D2, D3, D4, D5, D6, D7, DELAY, BL> GpioInterface8Bit<RS, EN, D0, D1, D2, D3, D4, D5, D6, D7, DELAY, BL>
+where
+    RS: OutputPin,
+    EN: OutputPin,
+    D0: OutputPin,
+    D1: OutputPin,
+    D2: OutputPin,
+    D3: OutputPin,
+    D4: OutputPin,
+    D5: OutputPin,
+    D6: OutputPin,
+    D7: OutputPin,
+    DELAY: AsyncDelayNs,
+    BL: OutputPin,
+{
+    // latches `byte` with a pulse on E
+    async fn write_byte_async(&mut self, byte: u8) -> Result<(), InterfaceError<ErrorKind>> {
+        self.set_byte(byte)?;
+        self.set_en(true)?;
+        self.delay.delay_us(1).await;
+        self.set_en(false)?;
+        self.delay.delay_us(1).await;
+        Ok(())
+    }
+}
+
+#[cfg(feature="async")]
+impl<RS, EN, D0, D1, D2, D3, D4, D5, D6, D7, DELAY, BL> AsyncInterfaceTrait for GpioInterface8Bit<RS, EN, D0, D1, D2, D3, D4, D5, D6, D7, DELAY, BL>
+where
+    RS: OutputPin,
+    EN: OutputPin,
+    D0: OutputPin,
+    D1: OutputPin,
+    D2: OutputPin,
+    D3: OutputPin,
+    D4: OutputPin,
+    D5: OutputPin,
+    D6: OutputPin,
+    D7: OutputPin,
+    DELAY: AsyncDelayNs,
+    BL: OutputPin,
+{
+    type Error = ErrorKind;
+
+    async fn init(
+        &mut self,
+        fnset_lines:FnsetLines,
+        fnset_font:FnsetFont
+    ) -> Result<(), InterfaceError<ErrorKind>>
+    {
+        let byte = CmdOptions::Fnset as u8 | FnsetDataLen::Bit8 as u8;
+
+        // wait for the supply voltage to settle
+        self.delay.delay_us(40_000).await;
+        self.set_rs(false)?;
+        self.set_en(false)?;
+
+        self.write_byte_async(byte).await?;
+        self.delay.delay_us(4_100).await;
+        self.write_byte_async(byte).await?;
+        self.delay.delay_us(100).await;
+        self.write_byte_async(byte).await?;
+        self.delay.delay_us(EXEC_TIME_US).await;
+
+        self.send_byte::<false>(
+            CmdOptions::Fnset as u8 | FnsetDataLen::Bit8 as u8 | fnset_lines as u8 | fnset_font as u8
+        ).await
+    }
+
+    async fn send_byte<const RS_VAL:bool>(
+        &mut self,
+        byte: u8
+    ) -> Result<(), InterfaceError<ErrorKind>>
+    {
+        self.set_rs(RS_VAL)?;
+        self.write_byte_async(byte).await?;
+        self.delay.delay_us(EXEC_TIME_US).await;
+        Ok(())
+    }
+
+    async fn send_bytes<const RS_VAL:bool>(
+        &mut self,
+        bytes: &[u8]
+    ) -> Result<(), InterfaceError<ErrorKind>>
+    {
+        for &byte in bytes {
+            self.send_byte::<RS_VAL>(byte).await?;
+        }
+        Ok(())
+    }
+
+    async fn receive_byte<const RS_VAL:bool>(
+        &mut self,
+        _byte: &mut u8
+    ) -> Result<(), InterfaceError<ErrorKind>>
+    {
+        Err(InterfaceError::ReadUnsupported)
+    }
+
+    async fn receive_bytes<const RS_VAL:bool>(
+        &mut self,
+        _bytes: &mut [u8]
+    ) -> Result<(), InterfaceError<ErrorKind>>
+    {
+        Err(InterfaceError::ReadUnsupported)
+    }
+
+    async fn delay_us(
+        &mut self,
+        us: u32
+    )
+    {
+        self.delay.delay_us(us).await;
+    }
+
+    async fn backlight(
+        &mut self,
+        bl:bool
+    ) -> Result<(), InterfaceError<ErrorKind>>
+    {
+        self.bl.set_state(bl.into()).map_err(|e| InterfaceError::Bus(e.kind()))
+    }
+}
+
+
+/// Bidirectional pin that can be switched between output and input mode.
+/// embedded-hal 1.0 has no such abstraction, implement it for the data pins of your HAL
+/// to use the [`GpioInterface4BitRw`].
+pub trait IoPin: InputPin + OutputPin {
+    fn set_input(&mut self) -> Result<(), Self::Error>;
+    fn set_output(&mut self) -> Result<(), Self::Error>;
+}
+
+
+/// Parallel 4-bit interface with a RW pin, the display is wired directly to MCU pins (RS, RW, E, D4-D7).
+/// The data pins are switched to input mode for reading, which allows reading the
+/// address counter, busy flag and RAM contents.
+pub struct GpioInterface4BitRw<RS, RW, EN, D4, D5, D6, D7, DELAY, BL = NoPin>
+where
+    RS: OutputPin,
+    RW: OutputPin,
+    EN: OutputPin,
+    D4: IoPin,
+    D5: IoPin,
+    D6: IoPin,
+    D7: IoPin,
+    BL: OutputPin,
+{
+    rs: RS,
+    rw: RW,
+    en: EN,
+    d4: D4,
+    d5: D5,
+    d6: D6,
+    d7: D7,
+    delay: DELAY,
+    bl: BL,
+    // the driver polls the busy flag after every byte, which makes the fixed delay unnecessary
+    polled: bool,
+}
+
+impl<RS, RW, EN, D4, D5, D6, D7, DELAY> GpioInterface4BitRw<RS, RW, EN, D4, D5, D6, D7, DELAY, NoPin>
+where
+    RS: OutputPin,
+    RW: OutputPin,
+    EN: OutputPin,
+    D4: IoPin,
+    D5: IoPin,
+    D6: IoPin,
+    D7: IoPin,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(rs: RS, rw: RW, en: EN, d4: D4, d5: D5, d6: D6, d7: D7, delay: DELAY) -> Self {
+        Self {
+            rs,
+            rw,
+            en,
+            d4,
+            d5,
+            d6,
+            d7,
+            delay,
+            bl: NoPin,
+            polled: false,
+        }
+    }
+}
+
+impl<RS, RW, EN, D4, D5, D6, D7, DELAY, BL> GpioInterface4BitRw<RS, RW, EN, D4, D5, D6, D7, DELAY, BL>
+where
+    RS: OutputPin,
+    RW: OutputPin,
+    EN: OutputPin,
+    D4: IoPin,
+    D5: IoPin,
+    D6: IoPin,
+    D7: IoPin,
+    BL: OutputPin,
+{
+    /// Use `bl` to switch the backlight (high = on).
+    pub fn with_backlight<PIN: OutputPin>(self, bl: PIN) -> GpioInterface4BitRw<RS, RW, EN, D4, D5, D6, D7, DELAY, PIN> {
+        GpioInterface4BitRw {
+            rs: self.rs,
+            rw: self.rw,
+            en: self.en,
+            d4: self.d4,
+            d5: self.d5,
+            d6: self.d6,
+            d7: self.d7,
+            delay: self.delay,
+            bl,
+            polled: self.polled,
+        }
+    }
+
+    fn set_rs(&mut self, rs: bool) -> Result<(), InterfaceError<ErrorKind>> {
+        self.rs.set_state(rs.into()).map_err(|e| InterfaceError::Bus(e.kind()))
+    }
+
+    // RW high switches the data pins to input mode first, RW low switches them back to output mode
+    // afterwards, so display and MCU never drive the bus at the same time
+    fn set_rw(&mut self, read: bool) -> Result<(), InterfaceError<ErrorKind>> {
+        if read {
+            self.d4.set_input().map_err(|e| InterfaceError::Bus(e.kind()))?;
+            self.d5.set_input().map_err(|e| InterfaceError::Bus(e.kind()))?;
+            self.d6.set_input().map_err(|e| InterfaceError::Bus(e.kind()))?;
+            self.d7.set_input().map_err(|e| InterfaceError::Bus(e.kind()))?;
+            self.rw.set_high().map_err(|e| InterfaceError::Bus(e.kind()))
+        } else {
+            self.rw.set_low().map_err(|e| InterfaceError::Bus(e.kind()))?;
+            self.d4.set_output().map_err(|e| InterfaceError::Bus(e.kind()))?;
+            self.d5.set_output().map_err(|e| InterfaceError::Bus(e.kind()))?;
+            self.d6.set_output().map_err(|e| InterfaceError::Bus(e.kind()))?;
+            self.d7.set_output().map_err(|e| InterfaceError::Bus(e.kind()))
+        }
+    }
+
+    // puts the lower nibble of `nibble` on D4-D7
+    fn set_nibble(&mut self, nibble: u8) -> Result<(), InterfaceError<ErrorKind>> {
+        self.d4.set_state((nibble & 0x01 != 0).into()).map_err(|e| InterfaceError::Bus(e.kind()))?;
+        self.d5.set_state((nibble & 0x02 != 0).into()).map_err(|e| InterfaceError::Bus(e.kind()))?;
+        self.d6.set_state((nibble & 0x04 != 0).into()).map_err(|e| InterfaceError::Bus(e.kind()))?;
+        self.d7.set_state((nibble & 0x08 != 0).into()).map_err(|e| InterfaceError::Bus(e.kind()))?;
+        Ok(())
+    }
+
+    // samples D4-D7 into the lower nibble
+    fn get_nibble(&mut self) -> Result<u8, InterfaceError<ErrorKind>> {
+        Ok(
+            (self.d4.is_high().map_err(|e| InterfaceError::Bus(e.kind()))? as u8) |
+            (self.d5.is_high().map_err(|e| InterfaceError::Bus(e.kind()))? as u8) << 1 |
+            (self.d6.is_high().map_err(|e| InterfaceError::Bus(e.kind()))? as u8) << 2 |
+            (self.d7.is_high().map_err(|e| InterfaceError::Bus(e.kind()))? as u8) << 3
+        )
+    }
+
+    fn set_en(&mut self, en: bool) -> Result<(), InterfaceError<ErrorKind>> {
+        self.en.set_state(en.into()).map_err(|e| InterfaceError::Bus(e.kind()))
+    }
+}
+
+
+impl<RS, RW, EN, D4, D5, D6, D7, DELAY, BL> GpioInterface4BitRw<RS, RW, EN, D4, D5, D6, D7, DELAY, BL>
+where
+    RS: OutputPin,
+    RW: OutputPin,
+    EN: OutputPin,
+    D4: IoPin,
+    D5: IoPin,
+    D6: IoPin,
+    D7: IoPin,
+    DELAY: DelayNs,
+    BL: OutputPin,
+{
+    // latches the lower nibble of `nibble` with a pulse on E
+    fn write_nibble(&mut self, nibble: u8) -> Result<(), InterfaceError<ErrorKind>> {
+        self.set_nibble(nibble)?;
+        self.set_en(true)?;
+        self.delay.delay_us(1);
+        self.set_en(false)?;
+        self.delay.delay_us(1);
+        Ok(())
+    }
+
+    // samples a nibble while E is high
+    fn read_nibble(&mut self) -> Result<u8, InterfaceError<ErrorKind>> {
+        self.set_en(true)?;
+        self.delay.delay_us(1);
+        let nibble = self.get_nibble()?;
+        self.set_en(false)?;
+        self.delay.delay_us(1);
+        Ok(nibble)
+    }
+
+    fn write_byte(&mut self, rs: bool, byte: u8) -> Result<(), InterfaceError<ErrorKind>> {
+        self.set_rs(rs)?;
+        self.write_nibble(byte >> 4)?;
+        self.write_nibble(byte)
+    }
+}
+
+impl<RS, RW, EN, D4, D5, D6, D7, DELAY, BL> InterfaceTrait for GpioInterface4BitRw<RS, RW, EN, D4, D5, D6, D7, DELAY, BL>
+where
+    RS: OutputPin,
+    RW: OutputPin,
+    EN: OutputPin,
+    D4: IoPin,
+    D5: IoPin,
+    D6: IoPin,
+    D7: IoPin,
+    DELAY: DelayNs,
+    BL: OutputPin,
+{
+    type Error = ErrorKind;
+
+    fn init(&mut self, fnset_lines:FnsetLines, fnset_font:FnsetFont) -> Result<(), InterfaceError<ErrorKind>> {
+        let nibble = (CmdOptions::Fnset as u8 | FnsetDataLen::Bit8 as u8) >> 4;
+
+        // wait for the supply voltage to settle
+        self.delay.delay_us(40_000);
+        self.set_rs(false)?;
+        self.set_rw(false)?;
+        self.set_en(false)?;
+
+        self.write_nibble(nibble)?;
+        self.delay.delay_us(4_100);
+        self.write_nibble(nibble)?;
+        self.delay.delay_us(100);
+        self.write_nibble(nibble)?;
+        self.delay.delay_us(EXEC_TIME_US);
+
+        self.write_nibble((CmdOptions::Fnset as u8 | FnsetDataLen::Bit4 as u8) >> 4)?;
+        self.delay.delay_us(EXEC_TIME_US);
+
+        // now in 4-bit mode, the driver only polls after its own instructions
+
+        self.write_byte(false, CmdOptions::Fnset as u8 | FnsetDataLen::Bit4 as u8 | fnset_lines as u8 | fnset_font as u8)?;
+        self.delay.delay_us(EXEC_TIME_US);
+        Ok(())
+    }
+
+    fn send_byte<const RS_VAL:bool>(&mut self, byte: u8) -> Result<(), InterfaceError<ErrorKind>> {
+        self.write_byte(RS_VAL, byte)?;
+        if !self.polled {
+            self.delay.delay_us(EXEC_TIME_US);
+        }
+        Ok(())
+    }
+
+    fn receive_byte<const RS_VAL:bool>(&mut self, byte: &mut u8) -> Result<(), InterfaceError<ErrorKind>> {
+        self.set_rs(RS_VAL)?;
+        self.set_rw(true)?;
+        let msn = self.read_nibble();
+        let lsn = self.read_nibble();
+        // always hand the bus back to the MCU, even if sampling failed
+        self.set_rw(false)?;
+        *byte = (msn? << 4) | lsn?;
+        if RS_VAL {
+            // reading data RAM advances the address counter
+            self.delay.delay_us(EXEC_TIME_US);
+        }
+        Ok(())
+    }
+
+    fn backlight(&mut self, bl:bool) -> Result<(), InterfaceError<ErrorKind>> {
+        self.bl.set_state(bl.into()).map_err(|e| InterfaceError::Bus(e.kind()))
+    }
+
+    fn delay_us(&mut self, us: u32) {
+        self.delay.delay_us(us);
+    }
+
+    fn can_read(&self) -> bool {
+        true
+    }
+
+    fn set_busy_polling(&mut self, polling: bool) {
+        self.polled = polling;
+    }
+}
+
+
+#[cfg(feature="async")]
+impl<RS, RW, EN, D4, D5, D6, D7, DELAY, BL> GpioInterface4BitRw<RS, RW, EN, D4, D5, D6, D7, DELAY, BL>
+where
+    RS: OutputPin,
+    RW: OutputPin,
+    EN: OutputPin,
+    D4: IoPin,
+    D5: IoPin,
+    D6: IoPin,
+    D7: IoPin,
+    DELAY: AsyncDelayNs,
+    BL: OutputPin,
+{
+    // latches the lower nibble of `nibble` with a pulse on E
+    async fn write_nibble_async(&mut self, nibble: u8) -> Result<(), InterfaceError<ErrorKind>> {
+        self.set_nibble(nibble)?;
+        self.set_en(true)?;
+        self.delay.delay_us(1).await;
+        self.set_en(false)?;
+        self.delay.delay_us(1).await;
+        Ok(())
+    }
+
+    // samples a nibble while E is high
+    async fn read_nibble_async(&mut self) -> Result<u8, InterfaceError<ErrorKind>> {
+        self.set_en(true)?;
+        self.delay.delay_us(1).await;
+        let nibble = self.get_nibble()?;
+        self.set_en(false)?;
+        self.delay.delay_us(1).await;
+        Ok(nibble)
+    }
+
+    async fn write_byte_async(&mut self, rs: bool, byte: u8) -> Result<(), InterfaceError<ErrorKind>> {
+        self.set_rs(rs)?;
+        self.write_nibble_async(byte >> 4).await?;
+        self.write_nibble_async(byte).await
+    }
+}
+
+#[cfg(feature="async")]
+impl<RS, RW, EN, D4, D5, D6, D7, DELAY, BL> AsyncInterfaceTrait for GpioInterface4BitRw<RS, RW, EN, D4, D5, D6, D7, DELAY, BL>
+where
+    RS: OutputPin,
+    RW: OutputPin,
+    EN: OutputPin,
+    D4: IoPin,
+    D5: IoPin,
+    D6: IoPin,
+    D7: IoPin,
+    DELAY: AsyncDelayNs,
+    BL: OutputPin,
+{
+    type Error = ErrorKind;
+
+    async fn init(
+        &mut self,
+        fnset_lines:FnsetLines,
+        fnset_font:FnsetFont
+    ) -> Result<(), InterfaceError<ErrorKind>>
+    {
+        let nibble = (CmdOptions::Fnset as u8 | FnsetDataLen::Bit8 as u8) >> 4;
+
+        // wait for the supply voltage to settle
+        self.delay.delay_us(40_000).await;
+        self.set_rs(false)?;
+        self.set_rw(false)?;
+        self.set_en(false)?;
+
+        self.write_nibble_async(nibble).await?;
+        self.delay.delay_us(4_100).await;
+        self.write_nibble_async(nibble).await?;
+        self.delay.delay_us(100).await;
+        self.write_nibble_async(nibble).await?;
+        self.delay.delay_us(EXEC_TIME_US).await;
+
+        self.write_nibble_async((CmdOptions::Fnset as u8 | FnsetDataLen::Bit4 as u8) >> 4).await?;
+        self.delay.delay_us(EXEC_TIME_US).await;
+
+        // now in 4-bit mode, the driver only polls after its own instructions
+
+        self.write_byte_async(false, CmdOptions::Fnset as u8 | FnsetDataLen::Bit4 as u8 | fnset_lines as u8 | fnset_font as u8).await?;
+        self.delay.delay_us(EXEC_TIME_US).await;
+        Ok(())
+    }
+
+    async fn send_byte<const RS_VAL:bool>(
+        &mut self,
+        byte: u8
+    ) -> Result<(), InterfaceError<ErrorKind>>
+    {
+        self.write_byte_async(RS_VAL, byte).await?;
+        if !self.polled {
+            self.delay.delay_us(EXEC_TIME_US).await;
+        }
+        Ok(())
+    }
+
+    async fn send_bytes<const RS_VAL:bool>(
+        &mut self,
+        bytes: &[u8]
+    ) -> Result<(), InterfaceError<ErrorKind>>
+    {
+        for &byte in bytes {
+            self.send_byte::<RS_VAL>(byte).await?;
+        }
+        Ok(())
+    }
+
+    async fn receive_byte<const RS_VAL:bool>(
+        &mut self,
+        byte: &mut u8
+    ) -> Result<(), InterfaceError<ErrorKind>>
+    {
+        self.set_rs(RS_VAL)?;
+        self.set_rw(true)?;
+        let msn = self.read_nibble_async().await;
+        let lsn = self.read_nibble_async().await;
+        // always hand the bus back to the MCU, even if sampling failed
+        self.set_rw(false)?;
+        *byte = (msn? << 4) | lsn?;
+        if RS_VAL {
+            // reading data RAM advances the address counter
+            self.delay.delay_us(EXEC_TIME_US).await;
+        }
+        Ok(())
+    }
+
+    async fn receive_bytes<const RS_VAL:bool>(
+        &mut self,
+        bytes: &mut [u8]
+    ) -> Result<(), InterfaceError<ErrorKind>>
+    {
+        for byte in bytes {
+            self.receive_byte::<RS_VAL>(byte).await?;
+        }
+        Ok(())
+    }
+
+    async fn delay_us(
+        &mut self,
+        us: u32
+    )
+    {
+        self.delay.delay_us(us).await;
+    }
+
+    fn can_read(&self) -> bool {
+        true
+    }
+
+    fn set_busy_polling(&mut self, polling: bool) {
+        self.polled = polling;
+    }
+
+    async fn backlight(
+        &mut self,
+        bl:bool
+    ) -> Result<(), InterfaceError<ErrorKind>>
+    {
+        self.bl.set_state(bl.into()).map_err(|e| InterfaceError::Bus(e.kind()))
+    }
+}
@@ -1,31 +1,71 @@
 pub mod pcf8574;
+pub mod gpio;
+
+use core::fmt;
+
+use embedded_hal::{digital, i2c};
 
 use crate::instructions::{FnsetLines, FnsetFont};
 
 
+/// `E` is the error of the underlying bus, e.g. the I2C error of the HAL for the [`pcf8574`] interface
+/// or [`digital::ErrorKind`] for the [`gpio`] interfaces.
 #[derive(Debug, Clone, Copy)]
-pub enum InterfaceError {
-    Pcf8574I2cError
+pub enum InterfaceError<E> {
+    Bus(E),
+    ReadUnsupported,
+}
+
+impl<E: fmt::Debug> fmt::Display for InterfaceError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InterfaceError::Bus(e) => write!(f, "Bus Error ({:?})", e),
+            InterfaceError::ReadUnsupported => f.write_str("Reading not supported by Interface"),
+        }
+    }
+}
+
+impl<E: fmt::Debug> core::error::Error for InterfaceError<E> {}
+
+impl<E: i2c::Error> i2c::Error for InterfaceError<E> {
+    fn kind(&self) -> i2c::ErrorKind {
+        match self {
+            InterfaceError::Bus(e) => e.kind(),
+            InterfaceError::ReadUnsupported => i2c::ErrorKind::Other,
+        }
+    }
+}
+
+impl<E: digital::Error> digital::Error for InterfaceError<E> {
+    fn kind(&self) -> digital::ErrorKind {
+        match self {
+            InterfaceError::Bus(e) => e.kind(),
+            InterfaceError::ReadUnsupported => digital::ErrorKind::Other,
+        }
+    }
 }
 
 
-#[cfg(not(feature="async"))]
+/// Blocking interface to the display.
 pub trait InterfaceTrait
 {
-    fn init(&mut self, fnset_lines:FnsetLines, fnset_font:FnsetFont) -> Result<(), InterfaceError>;
+    /// Error of the underlying bus.
+    type Error: fmt::Debug;
+
+    fn init(&mut self, fnset_lines:FnsetLines, fnset_font:FnsetFont) -> Result<(), InterfaceError<Self::Error>>;
     
-    fn send_byte<const RS_VAL:bool>(&mut self, byte: u8) -> Result<(), InterfaceError>;
+    fn send_byte<const RS_VAL:bool>(&mut self, byte: u8) -> Result<(), InterfaceError<Self::Error>>;
 
-    fn send_bytes<const RS_VAL:bool>(&mut self, bytes: &[u8]) -> Result<(), InterfaceError> {
+    fn send_bytes<const RS_VAL:bool>(&mut self, bytes: &[u8]) -> Result<(), InterfaceError<Self::Error>> {
         for &byte in bytes {
             self.send_byte::<RS_VAL>(byte)?;
         }
         Ok(())
     }
 
-    fn receive_byte<const RS_VAL:bool>(&mut self, byte: &mut u8) -> Result<(), InterfaceError>;
+    fn receive_byte<const RS_VAL:bool>(&mut self, byte: &mut u8) -> Result<(), InterfaceError<Self::Error>>;
 
-    fn receive_bytes<const RS_VAL:bool>(&mut self, bytes: &mut [u8] ) -> Result<(), InterfaceError> {
+    fn receive_bytes<const RS_VAL:bool>(&mut self, bytes: &mut [u8] ) -> Result<(), InterfaceError<Self::Error>> {
         for byte in bytes {
             self.receive_byte::<RS_VAL>(byte)?;
         }
@@ -34,48 +74,70 @@ pub trait InterfaceTrait
 
     fn delay_us(&mut self, us:u32);
 
-    fn backlight(&mut self, bl:bool) -> Result<(), InterfaceError>;
+    /// Whether the wiring allows reading from the display (busy flag, address counter, RAM).
+    fn can_read(&self) -> bool {
+        false
+    }
+
+    /// Called by the driver with whether it waits on the busy flag after every byte it sends.
+    /// Interfaces which wait a fixed time after every byte can then skip it.
+    fn set_busy_polling(&mut self, _polling:bool) {}
+
+    fn backlight(&mut self, bl:bool) -> Result<(), InterfaceError<Self::Error>>;
 }
 
 
 
+/// Async interface to the display, available with the `async` feature.
 #[cfg(feature="async")]
-pub trait InterfaceTrait
+pub trait AsyncInterfaceTrait
 {
+    /// Error of the underlying bus.
+    type Error: fmt::Debug;
+
     fn init(
         &mut self, 
         fnset_lines:FnsetLines, 
         fnset_font:FnsetFont,
-    ) -> impl Future<Output = Result<(), InterfaceError>>;
+    ) -> impl Future<Output = Result<(), InterfaceError<Self::Error>>>;
     
     fn send_byte<const RS_VAL:bool>(
         &mut self, 
         byte: u8
-    ) -> impl Future<Output = Result<(), InterfaceError>>;
+    ) -> impl Future<Output = Result<(), InterfaceError<Self::Error>>>;
 
     fn send_bytes<const RS_VAL:bool>(
         &mut self,
         bytes: &[u8]
-    ) -> impl Future<Output = Result<(), InterfaceError>>;
+    ) -> impl Future<Output = Result<(), InterfaceError<Self::Error>>>;
 
     fn receive_byte<const RS_VAL:bool>(
         &mut self, 
         byte: &mut u8
-    ) -> impl Future<Output = Result<(), InterfaceError>>;
+    ) -> impl Future<Output = Result<(), InterfaceError<Self::Error>>>;
 
     fn receive_bytes<const RS_VAL:bool>(
         &mut self, 
         bytes: &mut [u8] 
-    ) -> impl Future<Output = Result<(), InterfaceError>>;
+    ) -> impl Future<Output = Result<(), InterfaceError<Self::Error>>>;
 
     fn delay_us(
         &mut self, 
         us:u32
     ) -> impl Future<Output = ()>;
 
+    /// Whether the wiring allows reading from the display (busy flag, address counter, RAM).
+    fn can_read(&self) -> bool {
+        false
+    }
+
+    /// Called by the driver with whether it waits on the busy flag after every byte it sends.
+    /// Interfaces which wait a fixed time after every byte can then skip it.
+    fn set_busy_polling(&mut self, _polling:bool) {}
+
     fn backlight(
         &mut self, 
         bl:bool
-    ) -> impl Future<Output = Result<(), InterfaceError>>;
+    ) -> impl Future<Output = Result<(), InterfaceError<Self::Error>>>;
 }
 
@@ -1,21 +1,23 @@
 
-#[cfg(not(feature="async"))]
-use embedded_hal::{delay, i2c};
+use embedded_hal::{delay::DelayNs, i2c::{self, I2c}};
 
 #[cfg(feature="async")]
-use embedded_hal_async::{delay, i2c};
+use embedded_hal_async::{delay::DelayNs as AsyncDelayNs, i2c::I2c as AsyncI2c};
 
 use crate::{
     instructions::{CmdOptions, FnsetDataLen, FnsetFont, FnsetLines}, 
     interface::{InterfaceError, InterfaceTrait}
 };
 
+#[cfg(feature="async")]
+use crate::interface::AsyncInterfaceTrait;
+
 
 
+/// Interface for the common PCF8574 I2C backpacks.
+/// Implements [`InterfaceTrait`] for blocking and [`AsyncInterfaceTrait`] for async I2C and delay types.
 pub struct Pcf8574Interface<I2C, DELAY, ENC>
 where 
-    I2C: i2c::I2c<i2c::SevenBitAddress>,
-    DELAY: delay::DelayNs,
     ENC: Pcf8574EncoderTrait,
 {
     i2c: I2C,
@@ -27,8 +29,6 @@ where
 
 impl<I2C, DELAY, ENC> Pcf8574Interface<I2C, DELAY, ENC>
 where
-    I2C: i2c::I2c,
-    DELAY: delay::DelayNs,
     ENC: Pcf8574EncoderTrait,
 {
     pub fn new(i2c: I2C, address: i2c::SevenBitAddress, delay: DELAY, enc: ENC) -> Self {
@@ -43,55 +43,56 @@ where
 }
 
 
-#[cfg(not(feature="async"))]
 impl<I2C, DELAY, ENC> InterfaceTrait for Pcf8574Interface<I2C, DELAY, ENC>
 where 
-    I2C: i2c::I2c,
-    DELAY: delay::DelayNs,
+    I2C: I2c,
+    DELAY: DelayNs,
     ENC: Pcf8574EncoderTrait,
 {
-    fn init(&mut self, fnset_lines:FnsetLines, fnset_font:FnsetFont) -> Result<(), super::InterfaceError> {
+    type Error = I2C::Error;
+
+    fn init(&mut self, fnset_lines:FnsetLines, fnset_font:FnsetFont) -> Result<(), InterfaceError<I2C::Error>> {
         let payload = self.enc.encode::<false, false>(self.bl, 
             CmdOptions::Fnset as u8 | FnsetDataLen::Bit8 as u8
         );
         
         self.i2c.write(self.address, 
             &payload[..2]
-        ).map_err(|_| InterfaceError::Pcf8574I2cError)?;
+        ).map_err(InterfaceError::Bus)?;
         self.delay.delay_us(4_100);
         self.i2c.write(self.address, 
             &payload[..2]
-        ).map_err(|_| InterfaceError::Pcf8574I2cError)?;
+        ).map_err(InterfaceError::Bus)?;
         self.delay.delay_us(100);
         self.i2c.write(self.address, 
             &payload[..2]
-        ).map_err(|_| InterfaceError::Pcf8574I2cError)?;
+        ).map_err(InterfaceError::Bus)?;
 
         let payload = self.enc.encode::<false, false>(self.bl,
             CmdOptions::Fnset as u8 | FnsetDataLen::Bit4 as u8 | fnset_lines as u8 | fnset_font as u8
         );
         self.i2c.write(self.address, 
             &payload[..2]
-        ).map_err(|_| InterfaceError::Pcf8574I2cError)?;
+        ).map_err(InterfaceError::Bus)?;
 
         // now in 4-bit mode
 
         self.i2c.write(self.address, 
             &payload
-        ).map_err(|_| InterfaceError::Pcf8574I2cError)?;
+        ).map_err(InterfaceError::Bus)?;
 
         Ok(())
     }
 
-    fn send_byte<const RS_VAL:bool>(&mut self, byte: u8) -> Result<(), super::InterfaceError> {
+    fn send_byte<const RS_VAL:bool>(&mut self, byte: u8) -> Result<(), InterfaceError<I2C::Error>> {
         self.i2c.write(
             self.address,
             &self.enc.encode::<RS_VAL, false>(self.bl, byte)
-        ).map_err(|_| InterfaceError::Pcf8574I2cError)?;
+        ).map_err(InterfaceError::Bus)?;
         Ok(())
     }
 
-    fn receive_byte<const RS_VAL:bool>(&mut self, byte: &mut u8) -> Result<(), super::InterfaceError> {
+    fn receive_byte<const RS_VAL:bool>(&mut self, byte: &mut u8) -> Result<(), InterfaceError<I2C::Error>> {
         let payload = self.enc.encode::<RS_VAL, true>(self.bl, 0x0f);
         // use payload[1..3] to prime read process
         
@@ -105,44 +106,48 @@ where
             i2c::Operation::Read(&mut lsn),
             i2c::Operation::Write(&payload[1..2]),
         ];
-        self.i2c.transaction(self.address, &mut transactions).map_err(
-            |_| InterfaceError::Pcf8574I2cError
-        )?;
+        self.i2c.transaction(self.address, &mut transactions).map_err(InterfaceError::Bus)?;
 
         *byte = self.enc.decode_data([msn[0], lsn[0]]);
 
         Ok(())
     }
 
-    fn backlight(&mut self, bl:bool) -> Result<(), InterfaceError> {
+    fn backlight(&mut self, bl:bool) -> Result<(), InterfaceError<I2C::Error>> {
         self.bl = bl;
         self.i2c.write(
             self.address, 
             &self.enc.encode::<false,false>(self.bl, 0x00)[1..2]
-        ).map_err(|_| InterfaceError::Pcf8574I2cError)?;
+        ).map_err(InterfaceError::Bus)?;
         Ok(())
     }
 
     fn delay_us(&mut self, us: u32) {
         self.delay.delay_us(us);
     }
+
+    fn can_read(&self) -> bool {
+        true
+    }
 }
 
 
 
 
 #[cfg(feature="async")]
-impl<I2C, DELAY, ENC> InterfaceTrait for Pcf8574Interface<I2C, DELAY, ENC>
+impl<I2C, DELAY, ENC> AsyncInterfaceTrait for Pcf8574Interface<I2C, DELAY, ENC>
 where 
-    I2C: i2c::I2c,
-    DELAY: delay::DelayNs,
+    I2C: AsyncI2c,
+    DELAY: AsyncDelayNs,
     ENC: Pcf8574EncoderTrait,
 {
+    type Error = I2C::Error;
+
     async fn init(
         &mut self, 
         fnset_lines:FnsetLines, 
         fnset_font:FnsetFont
-    ) -> Result<(), InterfaceError> 
+    ) -> Result<(), InterfaceError<I2C::Error>> 
     {
         let payload = self.enc.encode::<false, false>(self.bl, 
             CmdOptions::Fnset as u8 | FnsetDataLen::Bit8 as u8
@@ -150,28 +155,28 @@ where
         
         self.i2c.write(self.address, 
             &payload[..2]
-        ).await.map_err(|_| InterfaceError::Pcf8574I2cError)?;
+        ).await.map_err(InterfaceError::Bus)?;
         self.delay.delay_us(4_100).await;
         self.i2c.write(self.address, 
             &payload[..2]
-        ).await.map_err(|_| InterfaceError::Pcf8574I2cError)?;
+        ).await.map_err(InterfaceError::Bus)?;
         self.delay.delay_us(100).await;
         self.i2c.write(self.address, 
             &payload[..2]
-        ).await.map_err(|_| InterfaceError::Pcf8574I2cError)?;
+        ).await.map_err(InterfaceError::Bus)?;
 
         let payload = self.enc.encode::<false, false>(self.bl,
             CmdOptions::Fnset as u8 | FnsetDataLen::Bit4 as u8 | fnset_lines as u8 | fnset_font as u8
         );
         self.i2c.write(self.address, 
             &payload[..2]
-        ).await.map_err(|_| InterfaceError::Pcf8574I2cError)?;
+        ).await.map_err(InterfaceError::Bus)?;
 
         // now in 4-bit mode
 
         self.i2c.write(self.address, 
             &payload
-        ).await.map_err(|_| InterfaceError::Pcf8574I2cError)?;
+        ).await.map_err(InterfaceError::Bus)?;
 
         Ok(())
     }
@@ -179,19 +184,19 @@ where
     async fn send_byte<const RS_VAL:bool>(
         &mut self, 
         byte: u8
-    ) -> Result<(), super::InterfaceError> 
+    ) -> Result<(), InterfaceError<I2C::Error>> 
     {
         self.i2c.write(
             self.address,
             &self.enc.encode::<RS_VAL, false>(self.bl, byte)
-        ).await.map_err(|_| InterfaceError::Pcf8574I2cError)?;
+        ).await.map_err(InterfaceError::Bus)?;
         Ok(())
     }
      
     async fn send_bytes<const RS_VAL:bool>(
         &mut self,
         bytes: &[u8]
-    ) -> Result<(), InterfaceError>
+    ) -> Result<(), InterfaceError<I2C::Error>>
     {
         for &byte in bytes {
             self.send_byte::<RS_VAL>(byte).await?;
@@ -202,7 +207,7 @@ where
     async fn receive_byte<const RS_VAL:bool>(
         &mut self, 
         byte: &mut u8
-    ) -> Result<(), super::InterfaceError> 
+    ) -> Result<(), InterfaceError<I2C::Error>> 
     {
         let payload = self.enc.encode::<RS_VAL, true>(self.bl, 0x0f);
         // use payload[1..3] to prime read process
@@ -220,7 +225,7 @@ where
         self.i2c.transaction(
             self.address,
              &mut transactions
-        ).await.map_err(|_| InterfaceError::Pcf8574I2cError)?;
+        ).await.map_err(InterfaceError::Bus)?;
 
         *byte = self.enc.decode_data([msn[0], lsn[0]]);
 
@@ -230,7 +235,7 @@ where
     async fn receive_bytes<const RS_VAL:bool>(
         &mut self, 
         bytes: &mut [u8] 
-    ) -> Result<(), InterfaceError>
+    ) -> Result<(), InterfaceError<I2C::Error>>
     {
         for byte in bytes {
             self.receive_byte::<RS_VAL>(byte).await?;
@@ -246,16 +251,20 @@ where
         self.delay.delay_us(us).await;
     }
 
+    fn can_read(&self) -> bool {
+        true
+    }
+
     async fn backlight(
         &mut self, 
         bl:bool
-    ) -> Result<(), InterfaceError> 
+    ) -> Result<(), InterfaceError<I2C::Error>> 
     {
         self.bl = bl;
         self.i2c.write(
             self.address, 
             &self.enc.encode::<false,false>(self.bl, 0x00)[1..2]
-        ).await.map_err(|_| InterfaceError::Pcf8574I2cError)?;
+        ).await.map_err(InterfaceError::Bus)?;
         Ok(())
     }
 }
@@ -283,23 +292,41 @@ impl Pcf8574Encoder {
     }
 }
 
+impl Default for Pcf8574Encoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const RS:u8, const RNW:u8, const EN:u8, const BL:u8, const D4:u8, const D5:u8, const D6:u8, const D7:u8>
+Pcf8574Encoder<RS,RNW,EN,BL,D4,D5,D6,D7> {
+    // maps the lower nibble of `nibble` onto the D4..D7 pins
+    fn encode_nibble(nibble:u8) -> u8 {
+        (if nibble & 0x01 != 0 { D4 } else { 0 }) |
+        (if nibble & 0x02 != 0 { D5 } else { 0 }) |
+        (if nibble & 0x04 != 0 { D6 } else { 0 }) |
+        (if nibble & 0x08 != 0 { D7 } else { 0 })
+    }
+
+    // maps the D4..D7 pins back onto a lower nibble
+    fn decode_nibble(data:u8) -> u8 {
+        (if data & D4 != 0 { 0x01 } else { 0 }) |
+        (if data & D5 != 0 { 0x02 } else { 0 }) |
+        (if data & D6 != 0 { 0x04 } else { 0 }) |
+        (if data & D7 != 0 { 0x08 } else { 0 })
+    }
+}
+
 impl<const RS:u8, const RNW:u8, const EN:u8, const BL:u8, const D4:u8, const D5:u8, const D6:u8, const D7:u8> Pcf8574EncoderTrait 
 for Pcf8574Encoder<RS,RNW,EN,BL,D4,D5,D6,D7> {
     fn encode<const RS_VAL:bool, const RNW_VAL:bool>(&self, bl: bool, data:u8) -> [u8; 4] {
-        [EN | (RS_VAL as u8)*RS | (RNW_VAL as u8)*RNW | (bl as u8)*BL | ((data&0x10!=0)as u8)*D4 | ((data&0x20!=0)as u8)*D5 | ((data&0x40!=0)as u8)*D6 | ((data&0x80!=0)as u8)*D7, 
-              (RS_VAL as u8)*RS | (RNW_VAL as u8)*RNW | (bl as u8)*BL | ((data&0x10!=0)as u8)*D4 | ((data&0x20!=0)as u8)*D5 | ((data&0x40!=0)as u8)*D6 | ((data&0x80!=0)as u8)*D7,
-         EN | (RS_VAL as u8)*RS | (RNW_VAL as u8)*RNW | (bl as u8)*BL | ((data&0x01!=0)as u8)*D4 | ((data&0x02!=0)as u8)*D5 | ((data&0x04!=0)as u8)*D6 | ((data&0x08!=0)as u8)*D7, 
-              (RS_VAL as u8)*RS | (RNW_VAL as u8)*RNW | (bl as u8)*BL | ((data&0x01!=0)as u8)*D4 | ((data&0x02!=0)as u8)*D5 | ((data&0x04!=0)as u8)*D6 | ((data&0x08!=0)as u8)*D7,]
+        let ctrl = (if RS_VAL { RS } else { 0 }) | (if RNW_VAL { RNW } else { 0 }) | (if bl { BL } else { 0 });
+        let msn = Self::encode_nibble(data >> 4);
+        let lsn = Self::encode_nibble(data);
+        [EN | ctrl | msn, ctrl | msn, EN | ctrl | lsn, ctrl | lsn]
     }
     fn decode_data(&self, data: [u8;2]) -> u8 {
-        (((data[0] & D7) != 0) as u8) * 0x80 |
-        (((data[0] & D6) != 0) as u8) * 0x40 |
-        (((data[0] & D5) != 0) as u8) * 0x20 |
-        (((data[0] & D4) != 0) as u8) * 0x10 |
-        (((data[1] & D7) != 0) as u8) * 0x08 |
-        (((data[1] & D6) != 0) as u8) * 0x04 |
-        (((data[1] & D5) != 0) as u8) * 0x02 |
-        (((data[1] & D4) != 0) as u8) * 0x01
+        (Self::decode_nibble(data[0]) << 4) | Self::decode_nibble(data[1])
     }
 }
 
@@ -317,16 +344,20 @@ impl Pcf8574EncoderDefault {
     }
 }
 
+impl Default for Pcf8574EncoderDefault {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<const RS:u8, const RNW:u8, const EN:u8, const BL:u8> Pcf8574EncoderTrait 
 for Pcf8574EncoderDefault<RS,RNW,EN,BL> {
     fn encode<const RS_VAL:bool, const RNW_VAL:bool>(&self, bl: bool, data:u8) -> [u8; 4] {
-        [EN | (RS_VAL as u8)*RS | (RNW_VAL as u8)*RNW | (bl as u8)*BL | (data & 0xf0), 
-              (RS_VAL as u8)*RS | (RNW_VAL as u8)*RNW | (bl as u8)*BL | (data & 0xf0),
-         EN | (RS_VAL as u8)*RS | (RNW_VAL as u8)*RNW | (bl as u8)*BL | ((data & 0x0f) << 4), 
-              (RS_VAL as u8)*RS | (RNW_VAL as u8)*RNW | (bl as u8)*BL | ((data & 0x0f) << 4),]
+        let ctrl = (if RS_VAL { RS } else { 0 }) | (if RNW_VAL { RNW } else { 0 }) | (if bl { BL } else { 0 });
+        [EN | ctrl | (data & 0xf0), ctrl | (data & 0xf0),
+         EN | ctrl | ((data & 0x0f) << 4), ctrl | ((data & 0x0f) << 4)]
     }
     fn decode_data(&self, data: [u8;2]) -> u8 {
         (data[0] & 0xf0) | ((data[1] & 0xf0) >> 4)
     }
 }
-
@@ -5,47 +5,97 @@ pub mod interface;
 pub mod types;
 pub mod instructions;
 pub mod characters;
+pub mod framebuffer;
+pub mod canvas;
+pub mod bigfont;
+pub mod widgets;
+mod ram;
 
 pub use crate::instructions::*;
 
 use core::fmt::{self, Write, Arguments};
 
-const FMT_BUFFER_SIZE:usize = 64;
+#[cfg(feature="async")]
+const FMT_BUFFER_SIZE:usize = 128;
+const BUSY_POLL_INTERVAL_US:u32 = 10;
+const ENCODE_CHUNK_SIZE:usize = 16;
 
+/// `E` is the error type of the bus the display is attached to, see [`interface::InterfaceTrait::Error`].
 #[derive(Debug, Copy, Clone)]
-pub enum Hd44780Error {
-    InterfaceError(interface::InterfaceError),
+pub enum Hd44780Error<E> {
+    InterfaceError(interface::InterfaceError<E>),
     FmtError(core::fmt::Error),
     RowColOutOfRange,
+    BusyTimeout,
+    CharVerifyFailed,
+    CgramFull,
 }
 
-impl From<core::fmt::Error> for Hd44780Error
+impl<E> From<core::fmt::Error> for Hd44780Error<E>
 {
     fn from(err: core::fmt::Error) -> Self {
         Hd44780Error::FmtError(err)
     }
 }
 
-impl From<&Hd44780Error> for &'static str
+impl<E> From<interface::InterfaceError<E>> for Hd44780Error<E>
+{
+    fn from(err: interface::InterfaceError<E>) -> Self {
+        Hd44780Error::InterfaceError(err)
+    }
+}
+
+impl<E> From<&Hd44780Error<E>> for &'static str
 {
-    fn from(err: &Hd44780Error) -> Self {
+    fn from(err: &Hd44780Error<E>) -> Self {
         match err {
             Hd44780Error::InterfaceError(_) => "Inteface Error",
             Hd44780Error::FmtError(_) => "Formatting Error",
-            Hd44780Error::RowColOutOfRange => "Row or Column out of Range"
+            Hd44780Error::RowColOutOfRange => "Row or Column out of Range",
+            Hd44780Error::BusyTimeout => "Busy Flag Timeout",
+            Hd44780Error::CharVerifyFailed => "Custom Character Verification Failed",
+            Hd44780Error::CgramFull => "All Custom Characters Shown",
         }
     }
 }
 
+impl<E: fmt::Debug> fmt::Display for Hd44780Error<E>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg: &'static str = self.into();
+        match self {
+            Hd44780Error::InterfaceError(e) => write!(f, "{}: {}", msg, e),
+            _ => f.write_str(msg),
+        }
+    }
+}
 
+impl<E: fmt::Debug + 'static> core::error::Error for Hd44780Error<E>
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Hd44780Error::InterfaceError(e) => Some(e),
+            Hd44780Error::FmtError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+
+// Collects the formatted output from `skip` bytes on until the buffer is full, formatting then fails
+// and `full` is set. `skip` has to fall on a char boundary, the content always ends on one.
+#[cfg(feature="async")]
 struct Buffer<'a> {
     buf : &'a mut[u8],
     len : usize,
+    skip : usize,
+    full : bool,
 }
 
+#[cfg(feature="async")]
 impl<'a> Buffer<'a> {
-    pub fn new(buf:&'a mut [u8]) -> Self {
-        Self { buf, len:0 }
+    pub fn new(buf:&'a mut [u8], skip:usize) -> Self {
+        Self { buf, len:0, skip, full:false }
     }
 
     pub fn as_str(&self) -> &str {
@@ -53,19 +103,52 @@ impl<'a> Buffer<'a> {
     }
 }
 
+#[cfg(feature="async")]
 impl<'a> Write for Buffer<'a> {
     fn write_str(&mut self, s: &str) -> core::fmt::Result {
-        let b = s.as_bytes();
-        if self.len + b.len() > self.buf.len() {
-            return Err(fmt::Error);
+        let skipped = self.skip.min(s.len());
+        self.skip -= skipped;
+        for c in s[skipped..].chars() {
+            let end = self.len + c.len_utf8();
+            if end > self.buf.len() {
+                self.full = true;
+                return Err(fmt::Error);
+            }
+            c.encode_utf8(&mut self.buf[self.len..end]);
+            self.len = end;
         }
-        self.buf[self.len..self.len+b.len()].clone_from_slice(b);
-        self.len += b.len();
         Ok(())
     }
 }
 
 
+// Forwards formatted output to the display and keeps the error, which `fmt::Error` can not carry.
+struct FmtWriter<'a, INTERFACE, DPTYPE>
+where
+    INTERFACE: interface::InterfaceTrait,
+    DPTYPE: types::DisplayTypeTrait,
+{
+    dp: &'a mut Hd44780<INTERFACE, DPTYPE>,
+    err: Option<Hd44780Error<INTERFACE::Error>>,
+}
+
+impl<INTERFACE, DPTYPE> Write for FmtWriter<'_, INTERFACE, DPTYPE>
+where
+    INTERFACE: interface::InterfaceTrait,
+    DPTYPE: types::DisplayTypeTrait,
+{
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        match self.dp.print_string(s) {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                self.err = Some(e);
+                Err(fmt::Error)
+            }
+        }
+    }
+}
+
+
 pub struct Hd44780<INTERFACE, DPTYPE>
 where 
     INTERFACE: interface::InterfaceTrait,
@@ -73,11 +156,46 @@ where
 {
     interface: INTERFACE,
     dp_type: DPTYPE,
+    busy_timeout_us: Option<u32>,
+    rom: Option<characters::Rom>,
+    replacement: u8,
+    // software copy of the address counter, used when the interface can not read it back
+    ac: u8,
+    entry_mode: u8,
+    verify_chars: bool,
+    rom_fallback: bool,
+    // name of the `CharSet` in CG RAM, forgotten whenever a custom character is written
+    charset: Option<&'static str>,
+    ddram: ram::Ddram,
+    cgram: ram::Cgram,
 }
 
 
-#[cfg(not(feature="async"))]
-impl<'a, INTERFACE, DPTYPE> Hd44780<INTERFACE, DPTYPE>
+/// Async counterpart of [`Hd44780`], available with the `async` feature.
+#[cfg(feature="async")]
+pub struct Hd44780Async<INTERFACE, DPTYPE>
+where
+    INTERFACE: interface::AsyncInterfaceTrait,
+    DPTYPE: types::DisplayTypeTrait,
+{
+    interface: INTERFACE,
+    dp_type: DPTYPE,
+    busy_timeout_us: Option<u32>,
+    rom: Option<characters::Rom>,
+    replacement: u8,
+    // software copy of the address counter, used when the interface can not read it back
+    ac: u8,
+    entry_mode: u8,
+    verify_chars: bool,
+    rom_fallback: bool,
+    // name of the `CharSet` in CG RAM, forgotten whenever a custom character is written
+    charset: Option<&'static str>,
+    ddram: ram::Ddram,
+    cgram: ram::Cgram,
+}
+
+
+impl<INTERFACE, DPTYPE> Hd44780<INTERFACE, DPTYPE>
 where
     INTERFACE: interface::InterfaceTrait,
     DPTYPE: types::DisplayTypeTrait,
@@ -86,58 +204,104 @@ where
         Self {
             interface,
             dp_type,
+            busy_timeout_us: None,
+            rom: None,
+            replacement: b'?',
+            ac: 0,
+            entry_mode: EntryDir::Inc as u8 | EntryAds::Off as u8,
+            verify_chars: false,
+            rom_fallback: false,
+            charset: None,
+            ddram: ram::Ddram::new(),
+            cgram: ram::Cgram::new(),
         }
     }
 
-    pub fn init(&mut self) -> Result<&mut Self, Hd44780Error> {
+    pub fn display_type(&self) -> &DPTYPE {
+        &self.dp_type
+    }
+
+    /// Wait on the busy flag after every instruction instead of fixed delays, giving up after `timeout_us`.
+    /// `None` (default) or an interface which can not read falls back to the fixed delays.
+    pub fn busy_polling(&mut self, timeout_us:Option<u32>) -> &mut Self {
+        self.busy_timeout_us = timeout_us;
+        self.interface.set_busy_polling(self.polling());
+        self
+    }
+
+    /// Select the character ROM of the display, strings are then encoded char by char for this ROM.
+    /// `None` (default) sends strings as raw bytes.
+    pub fn character_rom(&mut self, rom:Option<characters::Rom>) -> &mut Self {
+        self.rom = rom;
+        self
+    }
+
+    /// Character code printed instead of chars missing in the selected ROM, defaults to `'?'`.
+    pub fn replacement_char(&mut self, code:u8) -> &mut Self {
+        self.replacement = code;
+        self
+    }
+
+    /// Read every custom character back after `create_char` and fail with `CharVerifyFailed` on a mismatch.
+    /// Only done on interfaces which can read, defaults to `false`.
+    pub fn verify_chars(&mut self, verify:bool) -> &mut Self {
+        self.verify_chars = verify;
+        self
+    }
+
+    /// Substitute chars missing in the selected ROM when printing strings: by a ROM character which looks the same,
+    /// else by the glyph from [`characters::fallback`] in a custom character as with `alloc_char`.
+    /// The replacement char is only printed once all custom characters are shown, or if there is no glyph.
+    /// Custom characters which are not on the display may be overwritten, defaults to `false`.
+    pub fn rom_fallback(&mut self, fallback:bool) -> &mut Self {
+        self.rom_fallback = fallback;
+        self
+    }
+
+    pub fn init(&mut self) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
         self.interface.init(
             self.dp_type.lines(), 
             self.dp_type.font()
-        ).map_err(|e| Hd44780Error::InterfaceError(e))?;
+        ).map_err(Hd44780Error::InterfaceError)?;
 
         self.display(DpState::On, DpCursor::Off, DpBlink::Off)?;
         self.clear()?;
         Ok(self)
     }
 
-    pub fn clear(&mut self) -> Result<&mut Self, Hd44780Error> {
-        self.interface.send_byte::<false>(
-            CmdOptions::Clear as u8
-        ).map_err(|e| Hd44780Error::InterfaceError(e))?;
-        self.interface.delay_us(1_520);
+    pub fn clear(&mut self) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        self.command(CmdOptions::Clear as u8, 1_520)?;
+        self.ac = 0;
+        self.ddram.clear();
         Ok(self)
     }
 
-    pub fn home(&mut self) -> Result<&mut Self, Hd44780Error> {
-        self.interface.send_byte::<false>(
-            CmdOptions::Home as u8
-        ).map_err(|e| Hd44780Error::InterfaceError(e))?;
-        self.interface.delay_us(1_520);
+    pub fn home(&mut self) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        self.command(CmdOptions::Home as u8, 1_520)?;
+        self.ac = 0;
         Ok(self)
     }
 
-    pub fn entry(&mut self, dir:EntryDir, ads:EntryAds) -> Result<&mut Self, Hd44780Error> {
-        self.interface.send_byte::<false>(
-            CmdOptions::Entry as u8 | dir as u8 | ads as u8
-        ).map_err(|e| Hd44780Error::InterfaceError(e))?;
+    pub fn entry(&mut self, dir:EntryDir, ads:EntryAds) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        self.command(CmdOptions::Entry as u8 | dir as u8 | ads as u8, 0)?;
+        self.entry_mode = dir as u8 | ads as u8;
         Ok(self)
     }
 
-    pub fn display(&mut self, state:DpState, cursor:DpCursor, blink:DpBlink) -> Result<&mut Self, Hd44780Error> {
-        self.interface.send_byte::<false>(
-            CmdOptions::Dp as u8 | state as u8 | cursor as u8 | blink as u8
-        ).map_err(|e| Hd44780Error::InterfaceError(e))?;
+    pub fn display(&mut self, state:DpState, cursor:DpCursor, blink:DpBlink) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        self.command(CmdOptions::Dp as u8 | state as u8 | cursor as u8 | blink as u8, 0)?;
         Ok(self)
     }
 
-    pub fn shift(&mut self, dp_type:ShiftType, dir:ShiftDir) -> Result<&mut Self, Hd44780Error> {
-        self.interface.send_byte::<false>(
-            CmdOptions::Shift as u8 | dp_type as u8 | dir as u8
-        ).map_err(|e| Hd44780Error::InterfaceError(e))?;
+    pub fn shift(&mut self, dp_type:ShiftType, dir:ShiftDir) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        self.command(CmdOptions::Shift as u8 | dp_type as u8 | dir as u8, 0)?;
+        if let ShiftType::Cursor = dp_type {
+            self.ac = ram::advance(self.ac, 1, matches!(dir, ShiftDir::Right), self.dp_type.lines());
+        }
         Ok(self)
     }
 
-    pub fn position(&mut self, row:u8, col:u8) -> Result<&mut Self, Hd44780Error> {
+    pub fn position(&mut self, row:u8, col:u8) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
         if row >= self.dp_type.rows() || col >= self.dp_type.cols() {
             return Err(Hd44780Error::RowColOutOfRange);
         }
@@ -146,98 +310,382 @@ where
             FnsetLines::One => self.dp_type.cols() * row + col,
             FnsetLines::Two => 0x40 * (row % 2) + self.dp_type.cols() * (row / 2) + col,
         };
-        self.interface.send_byte::<false>(
-            CmdOptions::SetDd as u8 | dd
-        ).map_err(|e| Hd44780Error::InterfaceError(e))?;
+        self.set_ddram(dd)?;
         Ok(self)
     }
 
-    pub fn print_string(&mut self, string:&str) -> Result<&mut Self, Hd44780Error> {
-        self.interface.send_bytes::<true>(
-            string.as_bytes()
-        ).map_err(|e| Hd44780Error::InterfaceError(e))?;
+    /// Prints `string` encoded for the ROM selected with `character_rom`, or as raw bytes without.
+    pub fn print_string(&mut self, string:&str) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        match self.rom {
+            None => self.send_data(string.as_bytes())?,
+            Some(rom) => {
+                let mut chunk = [0u8; ENCODE_CHUNK_SIZE];
+                let mut len = 0;
+                for c in string.chars() {
+                    chunk[len] = match rom.encode_for(c, self.dp_type.font()) {
+                        Some(code) => code,
+                        None => self.substitute(rom, c)?,
+                    };
+                    len += 1;
+                    if len == chunk.len() {
+                        self.send_data(&chunk)?;
+                        len = 0;
+                    }
+                }
+                self.send_data(&chunk[..len])?;
+            }
+        }
         Ok(self)
     }
 
-    pub fn print_fmt(&mut self, args:Arguments<'_>) -> Result<&mut Self, Hd44780Error> {
-        let mut data:[u8;FMT_BUFFER_SIZE] = [0;FMT_BUFFER_SIZE];
-        let mut buf = Buffer::new(&mut data);
-        match buf.write_fmt(args) {
-            Err(e) => Err(Hd44780Error::FmtError(e)),
-            Ok(_) => self.print_string(buf.as_str())
+    /// Sends raw character codes, e.g. custom chars (0x00-0x07) or ROM characters above 0x7f.
+    pub fn print_bytes(&mut self, bytes:&[u8]) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        self.send_data(bytes)?;
+        Ok(self)
+    }
+
+    /// Streams the formatted output straight to the display, there is no length limit.
+    pub fn print_fmt(&mut self, args:Arguments<'_>) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        let mut writer = FmtWriter { dp: self, err: None };
+        match writer.write_fmt(args) {
+            Err(e) => Err(writer.err.unwrap_or(Hd44780Error::FmtError(e))),
+            Ok(_) => Ok(self)
         }
     }
 
-    pub fn backlight(&mut self, bl:bool) -> Result<&mut Self, Hd44780Error> {
+    pub fn backlight(&mut self, bl:bool) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
         self.interface.backlight(bl).map_err(
-            |e| Hd44780Error::InterfaceError(e)
+            Hd44780Error::InterfaceError
         )?;
         Ok(self)
     }
 
-    pub fn read_data(&mut self, buffer:&mut [u8]) -> Result<&mut Self, Hd44780Error> {
+    pub fn read_data(&mut self, buffer:&mut [u8]) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
         self.interface.receive_bytes::<true>(buffer).map_err(
-            |e| Hd44780Error::InterfaceError(e)
+            Hd44780Error::InterfaceError
         )?;
+        self.ac = ram::advance(self.ac, buffer.len(), self.entry_inc(), self.dp_type.lines());
         Ok(self)
     }
 
-    pub fn read_address_counter(&mut self) -> Result<u8, Hd44780Error> {
+    pub fn read_address_counter(&mut self) -> Result<u8, Hd44780Error<INTERFACE::Error>> {
         let mut ac: u8 = 0;
         self.interface.receive_byte::<false>(&mut ac).map_err(
-            |e| Hd44780Error::InterfaceError(e)
+            Hd44780Error::InterfaceError
         )?;
         Ok(ac & 0x7f)
     }
 
-    pub fn is_busy(&mut self) -> Result<bool, Hd44780Error> {
+    pub fn is_busy(&mut self) -> Result<bool, Hd44780Error<INTERFACE::Error>> {
         let mut ac: u8 = 0;
         self.interface.receive_byte::<false>(&mut ac).map_err(
-            |e| Hd44780Error::InterfaceError(e)
+            Hd44780Error::InterfaceError
         )?;
         Ok((ac & 0x80) != 0)
     }
+
+    fn polling(&self) -> bool {
+        self.busy_timeout_us.is_some() && self.interface.can_read()
+    }
+
+    // waits until the display accepts the next instruction, `exec_us` is the fixed delay used without busy polling
+    fn wait_ready(&mut self, exec_us:u32) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        match self.busy_timeout_us {
+            Some(timeout_us) if self.interface.can_read() => {
+                let mut waited_us = 0;
+                while self.is_busy()? {
+                    if waited_us >= timeout_us {
+                        return Err(Hd44780Error::BusyTimeout);
+                    }
+                    self.interface.delay_us(BUSY_POLL_INTERVAL_US);
+                    waited_us = waited_us.saturating_add(BUSY_POLL_INTERVAL_US);
+                }
+            },
+            _ => if exec_us > 0 {
+                self.interface.delay_us(exec_us);
+            },
+        }
+        Ok(())
+    }
+
+    fn command(&mut self, cmd:u8, exec_us:u32) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        self.interface.send_byte::<false>(cmd).map_err(Hd44780Error::InterfaceError)?;
+        self.wait_ready(exec_us)
+    }
+
+    fn send_data(&mut self, bytes:&[u8]) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        self.transfer(bytes)?;
+        self.ddram.write(self.ac, bytes, self.entry_inc(), self.dp_type.lines());
+        self.cgram.release();
+        self.ac = ram::advance(self.ac, bytes.len(), self.entry_inc(), self.dp_type.lines());
+        Ok(())
+    }
+
+    // sends data to whichever RAM the address counter points into
+    fn transfer(&mut self, bytes:&[u8]) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        if self.polling() {
+            for &byte in bytes {
+                self.interface.send_byte::<true>(byte).map_err(Hd44780Error::InterfaceError)?;
+                self.wait_ready(0)?;
+            }
+        } else {
+            self.interface.send_bytes::<true>(bytes).map_err(Hd44780Error::InterfaceError)?;
+        }
+        Ok(())
+    }
+
+    fn entry_inc(&self) -> bool {
+        self.entry_mode & EntryDir::Inc as u8 != 0
+    }
+
+    // character code of `c` as printed by `print_string`
+    fn encode(&self, c:char) -> u8 {
+        match self.rom {
+            Some(rom) => rom.encode_for(c, self.dp_type.font()).unwrap_or(self.replacement),
+            None if c.is_ascii() => c as u8,
+            None => self.replacement,
+        }
+    }
+
+    // code printing `c`, which is missing in `rom`
+    fn substitute(&mut self, rom:characters::Rom, c:char) -> Result<u8, Hd44780Error<INTERFACE::Error>> {
+        if !self.rom_fallback {
+            return Ok(self.replacement);
+        }
+        if let Some(code) = characters::fallback::lookalike(c).and_then(|c| rom.encode(c)) {
+            return Ok(code);
+        }
+        if let Some(glyph) = characters::fallback::glyph(c) {
+            let font = self.dp_type.font();
+            let mut rows = [0u8; 10];
+            rows[..8].copy_from_slice(&glyph.rows());
+            match self.alloc_glyph(&rows[..ram::height(font)]) {
+                Ok(slot) => return Ok(ram::code(slot, font)),
+                Err(Hd44780Error::CgramFull) => {},
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(self.replacement)
+    }
+
+    fn set_ddram(&mut self, address:u8) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        self.command(CmdOptions::SetDd as u8 | address, 0)?;
+        self.ac = address;
+        Ok(())
+    }
+
+    // selects CG RAM at `address` with an incrementing address counter, as glyph rows are stored top to bottom,
+    // returns the DD RAM address to go back to
+    fn enter_cgram(&mut self, address:u8) -> Result<u8, Hd44780Error<INTERFACE::Error>> {
+        let ddram = if self.interface.can_read() {
+            self.read_address_counter()?
+        } else {
+            self.ac
+        };
+        if !self.entry_inc() {
+            self.command(CmdOptions::Entry as u8 | EntryDir::Inc as u8, 0)?;
+        }
+        self.command(CmdOptions::SetCg as u8 | (address & 0x3f), 0)?;
+        Ok(ddram)
+    }
+
+    // returns to DD RAM `address` and the entry mode set with `entry`
+    fn leave_cgram(&mut self, address:u8) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        if !self.entry_inc() {
+            self.command(CmdOptions::Entry as u8 | self.entry_mode, 0)?;
+        }
+        self.set_ddram(address)
+    }
+
+    fn write_cgram(&mut self, address:u8, rows:&[u8]) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        let ddram = self.enter_cgram(address)?;
+        self.transfer(rows)?;
+        self.leave_cgram(ddram)?;
+
+        if self.verify_chars && self.interface.can_read() {
+            // large enough for the whole CG RAM
+            let mut buf = [0u8; 64];
+            let read = &mut buf[..rows.len()];
+            self.read_cgram(address, read)?;
+            if read.iter().zip(rows).any(|(r, w)| r & 0x1f != w & 0x1f) {
+                return Err(Hd44780Error::CharVerifyFailed);
+            }
+        }
+        Ok(())
+    }
+
+    fn read_cgram(&mut self, address:u8, rows:&mut [u8]) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        if !self.interface.can_read() {
+            return Err(Hd44780Error::InterfaceError(interface::InterfaceError::ReadUnsupported));
+        }
+        let ddram = self.enter_cgram(address)?;
+        self.interface.receive_bytes::<true>(rows).map_err(Hd44780Error::InterfaceError)?;
+        self.leave_cgram(ddram)
+    }
+
+    fn write_glyph(&mut self, slot:usize, rows:&[u8]) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        self.write_cgram(ram::address(slot, self.dp_type.font()), rows)?;
+        self.charset = None;
+        self.cgram.store(slot, rows);
+        Ok(())
+    }
+
+    fn read_glyph(&mut self, slot:usize, rows:&mut [u8]) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        self.read_cgram(ram::address(slot, self.dp_type.font()), rows)?;
+        for row in rows.iter_mut() {
+            *row &= 0x1f;
+        }
+        Ok(())
+    }
+
+    // slot holding `rows`, loaded into the least recently used slot which is not shown if necessary
+    fn alloc_glyph(&mut self, rows:&[u8]) -> Result<usize, Hd44780Error<INTERFACE::Error>> {
+        let font = self.dp_type.font();
+        let slot = match self.cgram.find(rows, font) {
+            Some(slot) => slot,
+            None => {
+                let shown = self.ddram.shown_slots(font);
+                let slot = self.cgram.victim(font, &shown).ok_or(Hd44780Error::CgramFull)?;
+                self.write_glyph(slot, rows)?;
+                slot
+            },
+        };
+        self.cgram.reserve(slot);
+        Ok(slot)
+    }
+
+    // prints `string` like `print_styled`, `ROWS` has to match the font of the display
+    fn write_styled<const ROWS: usize>(&mut self, string:&str, attr:characters::TextAttr, font:&impl characters::GlyphSource<ROWS>) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        let dp_font = self.dp_type.font();
+        let mut chunk = [0u8; ENCODE_CHUNK_SIZE];
+        let mut len = 0;
+        for c in string.chars() {
+            let code = self.encode(c);
+            chunk[len] = match font.glyph(code) {
+                Some(glyph) if attr != characters::TextAttr::Normal => {
+                    let rows = attr.apply(glyph).rows();
+                    ram::code(self.alloc_glyph(&rows[..ram::height(dp_font)])?, dp_font)
+                },
+                _ => code,
+            };
+            len += 1;
+            if len == chunk.len() {
+                self.send_data(&chunk)?;
+                len = 0;
+            }
+        }
+        self.send_data(&chunk[..len])?;
+        Ok(())
+    }
 }
 
-#[cfg(not(feature="async"))]
 impl<INTERFACE> Hd44780<INTERFACE, types::DisplayTypeFont5x8>
 where
     INTERFACE: interface::InterfaceTrait,
 {
-    pub fn create_char(&mut self, charcode: characters::CustomFont5x8, charmap:[u8;8]) -> Result<&mut Self, Hd44780Error> {
-        self.interface.send_byte::<false>(
-            CmdOptions::SetCg as u8 | ((charcode as u8) & 0b0000_0111)
-        ).map_err(
-            |e| Hd44780Error::InterfaceError(e)
-        )?;
-        self.interface.send_bytes::<true>(&charmap).map_err(
-            |e| Hd44780Error::InterfaceError(e)
-        )?;
+    pub fn create_char(&mut self, charcode: characters::CustomFont5x8, charmap:impl Into<[u8;8]>) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        self.write_glyph(charcode.slot(), &charmap.into())?;
+        Ok(self)
+    }
+
+    /// Reads the rows of a custom character back from CG RAM, needs an interface which can read.
+    pub fn read_char(&mut self, charcode: characters::CustomFont5x8) -> Result<[u8;8], Hd44780Error<INTERFACE::Error>> {
+        let mut charmap = [0u8; 8];
+        self.read_glyph(charcode.slot(), &mut charmap)?;
+        Ok(charmap)
+    }
+
+    /// Returns a custom character showing `charmap`, loading it into CG RAM only if no slot holds it yet.
+    /// The least recently requested slot which is not on the display is reused, `CgramFull` means
+    /// all eight are shown. Codes returned since the last print are kept as well,
+    /// so all characters of a line can be requested before printing it.
+    pub fn alloc_char(&mut self, charmap:impl Into<[u8;8]>) -> Result<characters::CustomFont5x8, Hd44780Error<INTERFACE::Error>> {
+        let slot = self.alloc_glyph(&charmap.into())?;
+        Ok(characters::CustomFont5x8::ALL[slot])
+    }
+
+    /// Prints `string` with `attr` drawn in software: the bitmap of every character is taken from `font`,
+    /// transformed and shown through a custom character from `alloc_char`, equal characters share one.
+    /// Characters `font` has no bitmap for are printed plainly. Up to eight distinct styled characters
+    /// fit on the display, `CgramFull` means more are needed. Printing the text again with
+    /// `TextAttr::Normal` restores it and lets its custom characters be reused.
+    pub fn print_styled(&mut self, string:&str, attr:characters::TextAttr, font:&impl characters::GlyphSource<8>) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        self.write_styled(string, attr, font)?;
         Ok(self)
     }
+
+    /// Loads all glyphs of `set` in a single transfer, glyph `i` becomes `CustomFont5x8::ALL[i]`.
+    /// Nothing is sent if the set is still loaded, i.e. no custom character was written since;
+    /// sets are told apart by their names.
+    pub fn load_charset(&mut self, set:&characters::CharSet) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        if self.charset != Some(set.name()) {
+            let mut rows = [0u8; 64];
+            for (chunk, glyph) in rows.chunks_exact_mut(8).zip(set.glyphs()) {
+                chunk.copy_from_slice(glyph);
+            }
+            self.write_cgram(0, &rows[..set.glyphs().len() * 8])?;
+            for (slot, glyph) in set.glyphs().iter().enumerate() {
+                self.cgram.store(slot, glyph);
+            }
+        }
+        self.charset = Some(set.name());
+        Ok(self)
+    }
+
+    /// Name of the set loaded last with `load_charset`, `None` once a custom character was changed since.
+    pub fn charset(&self) -> Option<&'static str> {
+        self.charset
+    }
 }
 
-#[cfg(not(feature="async"))]
-impl<'a, INTERFACE> Hd44780<INTERFACE, types::DisplayTypeFont5x10>
+impl<INTERFACE> Hd44780<INTERFACE, types::DisplayTypeFont5x10>
 where
     INTERFACE: interface::InterfaceTrait,
 {
-    pub fn create_char(&mut self, charcode:characters::CustomFont5x10, charmap:[u8;10]) -> Result<&mut Self, Hd44780Error> {
-        self.interface.send_byte::<false>(
-            CmdOptions::SetCg as u8 | ((charcode as u8) & 0b0000_0110)
-        ).map_err(
-            |e| Hd44780Error::InterfaceError(e)
-        )?;
-        self.interface.send_bytes::<true>(&charmap).map_err(
-            |e| Hd44780Error::InterfaceError(e)
-        )?;
+    pub fn create_char(&mut self, charcode:characters::CustomFont5x10, charmap:impl Into<[u8;10]>) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        self.write_glyph(charcode.slot(), &charmap.into())?;
+        Ok(self)
+    }
+
+    /// Reads the rows of a custom character back from CG RAM, needs an interface which can read.
+    pub fn read_char(&mut self, charcode:characters::CustomFont5x10) -> Result<[u8;10], Hd44780Error<INTERFACE::Error>> {
+        let mut charmap = [0u8; 10];
+        self.read_glyph(charcode.slot(), &mut charmap)?;
+        Ok(charmap)
+    }
+
+    /// Returns a custom character showing `charmap`, loading it into CG RAM only if no slot holds it yet.
+    /// The least recently requested slot which is not on the display is reused, `CgramFull` means
+    /// all four are shown. Codes returned since the last print are kept as well,
+    /// so all characters of a line can be requested before printing it.
+    pub fn alloc_char(&mut self, charmap:impl Into<[u8;10]>) -> Result<characters::CustomFont5x10, Hd44780Error<INTERFACE::Error>> {
+        let slot = self.alloc_glyph(&charmap.into())?;
+        Ok(characters::CustomFont5x10::ALL[slot])
+    }
+
+    /// Prints `string` with `attr` drawn in software: the bitmap of every character is taken from `font`,
+    /// transformed and shown through a custom character from `alloc_char`, equal characters share one.
+    /// Characters `font` has no bitmap for are printed plainly. Up to four distinct styled characters
+    /// fit on the display, `CgramFull` means more are needed. Printing the text again with
+    /// `TextAttr::Normal` restores it and lets its custom characters be reused.
+    pub fn print_styled(&mut self, string:&str, attr:characters::TextAttr, font:&impl characters::GlyphSource<10>) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        self.write_styled(string, attr, font)?;
         Ok(self)
     }
 }
 
+impl<INTERFACE, DPTYPE> Write for Hd44780<INTERFACE, DPTYPE>
+where
+    INTERFACE: interface::InterfaceTrait,
+    DPTYPE: types::DisplayTypeTrait,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.print_string(s).map(|_| ()).map_err(|_| fmt::Error)
+    }
+}
+
 
 #[macro_export]
-#[cfg(not(feature="async"))]
 macro_rules! lcd_write {
     ($dp:expr, $($arg:tt)*) => {
         $dp.print_fmt(format_args!($($arg)*))
@@ -247,67 +695,113 @@ macro_rules! lcd_write {
 
 
 #[cfg(feature="async")]
-impl<INTERFACE, DPTYPE> Hd44780<INTERFACE, DPTYPE>
+impl<INTERFACE, DPTYPE> Hd44780Async<INTERFACE, DPTYPE>
 where
-    INTERFACE: interface::InterfaceTrait,
+    INTERFACE: interface::AsyncInterfaceTrait,
     DPTYPE: types::DisplayTypeTrait,
 {
     pub fn new(interface: INTERFACE, dp_type: DPTYPE) -> Self {
         Self {
             interface,
             dp_type,
+            busy_timeout_us: None,
+            rom: None,
+            replacement: b'?',
+            ac: 0,
+            entry_mode: EntryDir::Inc as u8 | EntryAds::Off as u8,
+            verify_chars: false,
+            rom_fallback: false,
+            charset: None,
+            ddram: ram::Ddram::new(),
+            cgram: ram::Cgram::new(),
         }
     }
 
-    pub async fn init(&mut self) -> Result<&mut Self, Hd44780Error> {
+    pub fn display_type(&self) -> &DPTYPE {
+        &self.dp_type
+    }
+
+    /// Wait on the busy flag after every instruction instead of fixed delays, giving up after `timeout_us`.
+    /// `None` (default) or an interface which can not read falls back to the fixed delays.
+    pub fn busy_polling(&mut self, timeout_us:Option<u32>) -> &mut Self {
+        self.busy_timeout_us = timeout_us;
+        self.interface.set_busy_polling(self.polling());
+        self
+    }
+
+    /// Select the character ROM of the display, strings are then encoded char by char for this ROM.
+    /// `None` (default) sends strings as raw bytes.
+    pub fn character_rom(&mut self, rom:Option<characters::Rom>) -> &mut Self {
+        self.rom = rom;
+        self
+    }
+
+    /// Character code printed instead of chars missing in the selected ROM, defaults to `'?'`.
+    pub fn replacement_char(&mut self, code:u8) -> &mut Self {
+        self.replacement = code;
+        self
+    }
+
+    /// Read every custom character back after `create_char` and fail with `CharVerifyFailed` on a mismatch.
+    /// Only done on interfaces which can read, defaults to `false`.
+    pub fn verify_chars(&mut self, verify:bool) -> &mut Self {
+        self.verify_chars = verify;
+        self
+    }
+
+    /// Substitute chars missing in the selected ROM when printing strings: by a ROM character which looks the same,
+    /// else by the glyph from [`characters::fallback`] in a custom character as with `alloc_char`.
+    /// The replacement char is only printed once all custom characters are shown, or if there is no glyph.
+    /// Custom characters which are not on the display may be overwritten, defaults to `false`.
+    pub fn rom_fallback(&mut self, fallback:bool) -> &mut Self {
+        self.rom_fallback = fallback;
+        self
+    }
+
+    pub async fn init(&mut self) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
         self.interface.init(
             self.dp_type.lines(), 
             self.dp_type.font()
-        ).await.map_err(|e| Hd44780Error::InterfaceError(e))?;
+        ).await.map_err(Hd44780Error::InterfaceError)?;
 
         self.display(DpState::On, DpCursor::Off, DpBlink::Off).await?;
         self.clear().await?;
         Ok(self)
     }
 
-    pub async fn clear(&mut self) -> Result<&mut Self, Hd44780Error> {
-        self.interface.send_byte::<false>(
-            CmdOptions::Clear as u8
-        ).await.map_err(|e| Hd44780Error::InterfaceError(e))?;
-        self.interface.delay_us(1_520).await;
+    pub async fn clear(&mut self) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        self.command(CmdOptions::Clear as u8, 1_520).await?;
+        self.ac = 0;
+        self.ddram.clear();
         Ok(self)
     }
 
-    pub async fn home(&mut self) -> Result<&mut Self, Hd44780Error> {
-        self.interface.send_byte::<false>(
-            CmdOptions::Home as u8
-        ).await.map_err(|e| Hd44780Error::InterfaceError(e))?;
-        self.interface.delay_us(1_520).await;
+    pub async fn home(&mut self) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        self.command(CmdOptions::Home as u8, 1_520).await?;
+        self.ac = 0;
         Ok(self)
     }
 
-    pub async fn entry(&mut self, dir:EntryDir, ads:EntryAds) -> Result<&mut Self, Hd44780Error> {
-        self.interface.send_byte::<false>(
-            CmdOptions::Entry as u8 | dir as u8 | ads as u8
-        ).await.map_err(|e| Hd44780Error::InterfaceError(e))?;
+    pub async fn entry(&mut self, dir:EntryDir, ads:EntryAds) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        self.command(CmdOptions::Entry as u8 | dir as u8 | ads as u8, 0).await?;
+        self.entry_mode = dir as u8 | ads as u8;
         Ok(self)
     }
 
-    pub async fn display(&mut self, state:DpState, cursor:DpCursor, blink:DpBlink) -> Result<&mut Self, Hd44780Error> {
-        self.interface.send_byte::<false>(
-            CmdOptions::Dp as u8 | state as u8 | cursor as u8 | blink as u8
-        ).await.map_err(|e| Hd44780Error::InterfaceError(e))?;
+    pub async fn display(&mut self, state:DpState, cursor:DpCursor, blink:DpBlink) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        self.command(CmdOptions::Dp as u8 | state as u8 | cursor as u8 | blink as u8, 0).await?;
         Ok(self)
     }
 
-    pub async fn shift(&mut self, dp_type:ShiftType, dir:ShiftDir) -> Result<&mut Self, Hd44780Error> {
-        self.interface.send_byte::<false>(
-            CmdOptions::Shift as u8 | dp_type as u8 | dir as u8
-        ).await.map_err(|e| Hd44780Error::InterfaceError(e))?;
+    pub async fn shift(&mut self, dp_type:ShiftType, dir:ShiftDir) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        self.command(CmdOptions::Shift as u8 | dp_type as u8 | dir as u8, 0).await?;
+        if let ShiftType::Cursor = dp_type {
+            self.ac = ram::advance(self.ac, 1, matches!(dir, ShiftDir::Right), self.dp_type.lines());
+        }
         Ok(self)
     }
 
-    pub async fn position(&mut self, row:u8, col:u8) -> Result<&mut Self, Hd44780Error> {
+    pub async fn position(&mut self, row:u8, col:u8) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
         if row >= self.dp_type.rows() || col >= self.dp_type.cols() {
             return Err(Hd44780Error::RowColOutOfRange);
         }
@@ -316,108 +810,493 @@ where
             FnsetLines::One => self.dp_type.cols() * row + col,
             FnsetLines::Two => 0x40 * (row % 2) + self.dp_type.cols() * (row / 2) + col,
         };
-        self.interface.send_byte::<false>(
-            CmdOptions::SetDd as u8 | dd
-        ).await.map_err(|e| Hd44780Error::InterfaceError(e))?;
+        self.set_ddram(dd).await?;
         Ok(self)
     }
 
-    pub async fn print_string(&mut self, string:&str) -> Result<&mut Self, Hd44780Error> {
-        self.interface.send_bytes::<true>(
-            string.as_bytes()
-        ).await.map_err(|e| Hd44780Error::InterfaceError(e))?;
+    /// Prints `string` encoded for the ROM selected with `character_rom`, or as raw bytes without.
+    pub async fn print_string(&mut self, string:&str) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        match self.rom {
+            None => self.send_data(string.as_bytes()).await?,
+            Some(rom) => {
+                let mut chunk = [0u8; ENCODE_CHUNK_SIZE];
+                let mut len = 0;
+                for c in string.chars() {
+                    chunk[len] = match rom.encode_for(c, self.dp_type.font()) {
+                        Some(code) => code,
+                        None => self.substitute(rom, c).await?,
+                    };
+                    len += 1;
+                    if len == chunk.len() {
+                        self.send_data(&chunk).await?;
+                        len = 0;
+                    }
+                }
+                self.send_data(&chunk[..len]).await?;
+            }
+        }
+        Ok(self)
+    }
+
+    /// Sends raw character codes, e.g. custom chars (0x00-0x07) or ROM characters above 0x7f.
+    pub async fn print_bytes(&mut self, bytes:&[u8]) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        self.send_data(bytes).await?;
         Ok(self)
     }
 
     
-    pub async fn print_fmt(&mut self, args:Arguments<'_>) -> Result<&mut Self, Hd44780Error> {
-        let mut data:[u8;FMT_BUFFER_SIZE] = [0;FMT_BUFFER_SIZE];
-        let mut buf = Buffer::new(&mut data);
-        match buf.write_fmt(args) {
-            Err(e) => Err(Hd44780Error::FmtError(e)),
-            Ok(_) => self.print_string(buf.as_str()).await
+    /// Sends the formatted output in chunks of up to 128 bytes (UTF-8), there is no length limit.
+    /// `args` is formatted again for every chunk and has to give the same output each time.
+    pub async fn print_fmt(&mut self, args:Arguments<'_>) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        let mut sent = 0;
+        loop {
+            let mut data:[u8;FMT_BUFFER_SIZE] = [0;FMT_BUFFER_SIZE];
+            let mut buf = Buffer::new(&mut data, sent);
+            let result = buf.write_fmt(args);
+            if !buf.full {
+                result?;
+            }
+            self.print_string(buf.as_str()).await?;
+            if !buf.full {
+                return Ok(self);
+            }
+            sent += buf.len;
         }
     }
 
-    pub async fn backlight(&mut self, bl:bool) -> Result<&mut Self, Hd44780Error> {
+    pub async fn backlight(&mut self, bl:bool) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
         self.interface.backlight(bl)
         .await.map_err(
-            |e| Hd44780Error::InterfaceError(e)
+            Hd44780Error::InterfaceError
         )?;
         Ok(self)
     }
 
-    pub async fn read_data(&mut self, buffer:&mut [u8]) -> Result<&mut Self, Hd44780Error> {
+    pub async fn read_data(&mut self, buffer:&mut [u8]) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
         self.interface.receive_bytes::<true>(buffer)
         .await.map_err(
-            |e| Hd44780Error::InterfaceError(e)
+            Hd44780Error::InterfaceError
         )?;
+        self.ac = ram::advance(self.ac, buffer.len(), self.entry_inc(), self.dp_type.lines());
         Ok(self)
     }
 
-    pub async fn read_address_counter(&mut self) -> Result<u8, Hd44780Error> {
+    pub async fn read_address_counter(&mut self) -> Result<u8, Hd44780Error<INTERFACE::Error>> {
         let mut ac: u8 = 0;
         self.interface.receive_byte::<false>(&mut ac)
         .await.map_err(
-            |e| Hd44780Error::InterfaceError(e)
+            Hd44780Error::InterfaceError
         )?;
         Ok(ac & 0x7f)
     }
 
-    pub async fn is_busy(&mut self) -> Result<bool, Hd44780Error> {
+    pub async fn is_busy(&mut self) -> Result<bool, Hd44780Error<INTERFACE::Error>> {
         let mut ac: u8 = 0;
         self.interface.receive_byte::<false>(&mut ac)
         .await.map_err(
-            |e| Hd44780Error::InterfaceError(e)
+            Hd44780Error::InterfaceError
         )?;
         Ok((ac & 0x80) != 0)
     }
+
+    fn polling(&self) -> bool {
+        self.busy_timeout_us.is_some() && self.interface.can_read()
+    }
+
+    // waits until the display accepts the next instruction, `exec_us` is the fixed delay used without busy polling
+    async fn wait_ready(&mut self, exec_us:u32) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        match self.busy_timeout_us {
+            Some(timeout_us) if self.interface.can_read() => {
+                let mut waited_us = 0;
+                while self.is_busy().await? {
+                    if waited_us >= timeout_us {
+                        return Err(Hd44780Error::BusyTimeout);
+                    }
+                    self.interface.delay_us(BUSY_POLL_INTERVAL_US).await;
+                    waited_us = waited_us.saturating_add(BUSY_POLL_INTERVAL_US);
+                }
+            },
+            _ => if exec_us > 0 {
+                self.interface.delay_us(exec_us).await;
+            },
+        }
+        Ok(())
+    }
+
+    async fn command(&mut self, cmd:u8, exec_us:u32) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        self.interface.send_byte::<false>(cmd).await.map_err(Hd44780Error::InterfaceError)?;
+        self.wait_ready(exec_us).await
+    }
+
+    async fn send_data(&mut self, bytes:&[u8]) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        self.transfer(bytes).await?;
+        self.ddram.write(self.ac, bytes, self.entry_inc(), self.dp_type.lines());
+        self.cgram.release();
+        self.ac = ram::advance(self.ac, bytes.len(), self.entry_inc(), self.dp_type.lines());
+        Ok(())
+    }
+
+    // sends data to whichever RAM the address counter points into
+    async fn transfer(&mut self, bytes:&[u8]) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        if self.polling() {
+            for &byte in bytes {
+                self.interface.send_byte::<true>(byte).await.map_err(Hd44780Error::InterfaceError)?;
+                self.wait_ready(0).await?;
+            }
+        } else {
+            self.interface.send_bytes::<true>(bytes).await.map_err(Hd44780Error::InterfaceError)?;
+        }
+        Ok(())
+    }
+
+    fn entry_inc(&self) -> bool {
+        self.entry_mode & EntryDir::Inc as u8 != 0
+    }
+
+    // character code of `c` as printed by `print_string`
+    fn encode(&self, c:char) -> u8 {
+        match self.rom {
+            Some(rom) => rom.encode_for(c, self.dp_type.font()).unwrap_or(self.replacement),
+            None if c.is_ascii() => c as u8,
+            None => self.replacement,
+        }
+    }
+
+    // code printing `c`, which is missing in `rom`
+    async fn substitute(&mut self, rom:characters::Rom, c:char) -> Result<u8, Hd44780Error<INTERFACE::Error>> {
+        if !self.rom_fallback {
+            return Ok(self.replacement);
+        }
+        if let Some(code) = characters::fallback::lookalike(c).and_then(|c| rom.encode(c)) {
+            return Ok(code);
+        }
+        if let Some(glyph) = characters::fallback::glyph(c) {
+            let font = self.dp_type.font();
+            let mut rows = [0u8; 10];
+            rows[..8].copy_from_slice(&glyph.rows());
+            match self.alloc_glyph(&rows[..ram::height(font)]).await {
+                Ok(slot) => return Ok(ram::code(slot, font)),
+                Err(Hd44780Error::CgramFull) => {},
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(self.replacement)
+    }
+
+    async fn set_ddram(&mut self, address:u8) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        self.command(CmdOptions::SetDd as u8 | address, 0).await?;
+        self.ac = address;
+        Ok(())
+    }
+
+    // selects CG RAM at `address` with an incrementing address counter, as glyph rows are stored top to bottom,
+    // returns the DD RAM address to go back to
+    async fn enter_cgram(&mut self, address:u8) -> Result<u8, Hd44780Error<INTERFACE::Error>> {
+        let ddram = if self.interface.can_read() {
+            self.read_address_counter().await?
+        } else {
+            self.ac
+        };
+        if !self.entry_inc() {
+            self.command(CmdOptions::Entry as u8 | EntryDir::Inc as u8, 0).await?;
+        }
+        self.command(CmdOptions::SetCg as u8 | (address & 0x3f), 0).await?;
+        Ok(ddram)
+    }
+
+    // returns to DD RAM `address` and the entry mode set with `entry`
+    async fn leave_cgram(&mut self, address:u8) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        if !self.entry_inc() {
+            self.command(CmdOptions::Entry as u8 | self.entry_mode, 0).await?;
+        }
+        self.set_ddram(address).await
+    }
+
+    async fn write_cgram(&mut self, address:u8, rows:&[u8]) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        let ddram = self.enter_cgram(address).await?;
+        self.transfer(rows).await?;
+        self.leave_cgram(ddram).await?;
+
+        if self.verify_chars && self.interface.can_read() {
+            // large enough for the whole CG RAM
+            let mut buf = [0u8; 64];
+            let read = &mut buf[..rows.len()];
+            self.read_cgram(address, read).await?;
+            if read.iter().zip(rows).any(|(r, w)| r & 0x1f != w & 0x1f) {
+                return Err(Hd44780Error::CharVerifyFailed);
+            }
+        }
+        Ok(())
+    }
+
+    async fn read_cgram(&mut self, address:u8, rows:&mut [u8]) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        if !self.interface.can_read() {
+            return Err(Hd44780Error::InterfaceError(interface::InterfaceError::ReadUnsupported));
+        }
+        let ddram = self.enter_cgram(address).await?;
+        self.interface.receive_bytes::<true>(rows).await.map_err(Hd44780Error::InterfaceError)?;
+        self.leave_cgram(ddram).await
+    }
+
+    async fn write_glyph(&mut self, slot:usize, rows:&[u8]) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        self.write_cgram(ram::address(slot, self.dp_type.font()), rows).await?;
+        self.charset = None;
+        self.cgram.store(slot, rows);
+        Ok(())
+    }
+
+    async fn read_glyph(&mut self, slot:usize, rows:&mut [u8]) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        self.read_cgram(ram::address(slot, self.dp_type.font()), rows).await?;
+        for row in rows.iter_mut() {
+            *row &= 0x1f;
+        }
+        Ok(())
+    }
+
+    // slot holding `rows`, loaded into the least recently used slot which is not shown if necessary
+    async fn alloc_glyph(&mut self, rows:&[u8]) -> Result<usize, Hd44780Error<INTERFACE::Error>> {
+        let font = self.dp_type.font();
+        let slot = match self.cgram.find(rows, font) {
+            Some(slot) => slot,
+            None => {
+                let shown = self.ddram.shown_slots(font);
+                let slot = self.cgram.victim(font, &shown).ok_or(Hd44780Error::CgramFull)?;
+                self.write_glyph(slot, rows).await?;
+                slot
+            },
+        };
+        self.cgram.reserve(slot);
+        Ok(slot)
+    }
+
+    // prints `string` like `print_styled`, `ROWS` has to match the font of the display
+    async fn write_styled<const ROWS: usize>(&mut self, string:&str, attr:characters::TextAttr, font:&impl characters::GlyphSource<ROWS>) -> Result<(), Hd44780Error<INTERFACE::Error>> {
+        let dp_font = self.dp_type.font();
+        let mut chunk = [0u8; ENCODE_CHUNK_SIZE];
+        let mut len = 0;
+        for c in string.chars() {
+            let code = self.encode(c);
+            chunk[len] = match font.glyph(code) {
+                Some(glyph) if attr != characters::TextAttr::Normal => {
+                    let rows = attr.apply(glyph).rows();
+                    ram::code(self.alloc_glyph(&rows[..ram::height(dp_font)]).await?, dp_font)
+                },
+                _ => code,
+            };
+            len += 1;
+            if len == chunk.len() {
+                self.send_data(&chunk).await?;
+                len = 0;
+            }
+        }
+        self.send_data(&chunk[..len]).await?;
+        Ok(())
+    }
 }
 
 #[cfg(feature="async")]
-impl<INTERFACE> Hd44780<INTERFACE, types::DisplayTypeFont5x8>
+impl<INTERFACE> Hd44780Async<INTERFACE, types::DisplayTypeFont5x8>
 where
-    INTERFACE: interface::InterfaceTrait,
+    INTERFACE: interface::AsyncInterfaceTrait,
 {
-    pub async fn create_char(&mut self, charcode: characters::CustomFont5x8, charmap:[u8;8]) -> Result<&mut Self, Hd44780Error> {
-        self.interface.send_byte::<false>(
-            CmdOptions::SetCg as u8 | ((charcode as u8) & 0b0000_0111)
-        ).await.map_err(
-            |e| Hd44780Error::InterfaceError(e)
-        )?;
-        self.interface.send_bytes::<true>(&charmap).await.map_err(
-            |e| Hd44780Error::InterfaceError(e)
-        )?;
+    pub async fn create_char(&mut self, charcode: characters::CustomFont5x8, charmap:impl Into<[u8;8]>) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        self.write_glyph(charcode.slot(), &charmap.into()).await?;
+        Ok(self)
+    }
+
+    /// Reads the rows of a custom character back from CG RAM, needs an interface which can read.
+    pub async fn read_char(&mut self, charcode: characters::CustomFont5x8) -> Result<[u8;8], Hd44780Error<INTERFACE::Error>> {
+        let mut charmap = [0u8; 8];
+        self.read_glyph(charcode.slot(), &mut charmap).await?;
+        Ok(charmap)
+    }
+
+    /// Returns a custom character showing `charmap`, loading it into CG RAM only if no slot holds it yet.
+    /// The least recently requested slot which is not on the display is reused, `CgramFull` means
+    /// all eight are shown. Codes returned since the last print are kept as well,
+    /// so all characters of a line can be requested before printing it.
+    pub async fn alloc_char(&mut self, charmap:impl Into<[u8;8]>) -> Result<characters::CustomFont5x8, Hd44780Error<INTERFACE::Error>> {
+        let slot = self.alloc_glyph(&charmap.into()).await?;
+        Ok(characters::CustomFont5x8::ALL[slot])
+    }
+
+    /// Prints `string` with `attr` drawn in software: the bitmap of every character is taken from `font`,
+    /// transformed and shown through a custom character from `alloc_char`, equal characters share one.
+    /// Characters `font` has no bitmap for are printed plainly. Up to eight distinct styled characters
+    /// fit on the display, `CgramFull` means more are needed. Printing the text again with
+    /// `TextAttr::Normal` restores it and lets its custom characters be reused.
+    pub async fn print_styled(&mut self, string:&str, attr:characters::TextAttr, font:&impl characters::GlyphSource<8>) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        self.write_styled(string, attr, font).await?;
         Ok(self)
     }
+
+    /// Loads all glyphs of `set` in a single transfer, glyph `i` becomes `CustomFont5x8::ALL[i]`.
+    /// Nothing is sent if the set is still loaded, i.e. no custom character was written since;
+    /// sets are told apart by their names.
+    pub async fn load_charset(&mut self, set:&characters::CharSet) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        if self.charset != Some(set.name()) {
+            let mut rows = [0u8; 64];
+            for (chunk, glyph) in rows.chunks_exact_mut(8).zip(set.glyphs()) {
+                chunk.copy_from_slice(glyph);
+            }
+            self.write_cgram(0, &rows[..set.glyphs().len() * 8]).await?;
+            for (slot, glyph) in set.glyphs().iter().enumerate() {
+                self.cgram.store(slot, glyph);
+            }
+        }
+        self.charset = Some(set.name());
+        Ok(self)
+    }
+
+    /// Name of the set loaded last with `load_charset`, `None` once a custom character was changed since.
+    pub fn charset(&self) -> Option<&'static str> {
+        self.charset
+    }
 }
 
 #[cfg(feature="async")]
-impl<INTERFACE> Hd44780<INTERFACE, types::DisplayTypeFont5x10>
+impl<INTERFACE> Hd44780Async<INTERFACE, types::DisplayTypeFont5x10>
 where
-    INTERFACE: interface::InterfaceTrait,
+    INTERFACE: interface::AsyncInterfaceTrait,
 {
-    pub async fn create_char(&mut self, charcode:characters::CustomFont5x10, charmap:[u8;10]) -> Result<&mut Self, Hd44780Error> {
-        self.interface.send_byte::<false>(
-            CmdOptions::SetCg as u8 | ((charcode as u8) & 0b0000_0110)
-        ).await.map_err(
-            |e| Hd44780Error::InterfaceError(e)
-        )?;
-        self.interface.send_bytes::<true>(
-            &charmap
-        ).await.map_err(
-            |e| Hd44780Error::InterfaceError(e)
-        )?;
+    pub async fn create_char(&mut self, charcode:characters::CustomFont5x10, charmap:impl Into<[u8;10]>) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        self.write_glyph(charcode.slot(), &charmap.into()).await?;
         Ok(self)
     }
-}
 
+    /// Reads the rows of a custom character back from CG RAM, needs an interface which can read.
+    pub async fn read_char(&mut self, charcode:characters::CustomFont5x10) -> Result<[u8;10], Hd44780Error<INTERFACE::Error>> {
+        let mut charmap = [0u8; 10];
+        self.read_glyph(charcode.slot(), &mut charmap).await?;
+        Ok(charmap)
+    }
 
-#[macro_export]
-#[cfg(feature="async")]
-macro_rules! lcd_write {
-    ($dp:expr, $($arg:tt)*) => {
-        $dp.print_fmt(format_args!($($arg)*))
-    };
+    /// Returns a custom character showing `charmap`, loading it into CG RAM only if no slot holds it yet.
+    /// The least recently requested slot which is not on the display is reused, `CgramFull` means
+    /// all four are shown. Codes returned since the last print are kept as well,
+    /// so all characters of a line can be requested before printing it.
+    pub async fn alloc_char(&mut self, charmap:impl Into<[u8;10]>) -> Result<characters::CustomFont5x10, Hd44780Error<INTERFACE::Error>> {
+        let slot = self.alloc_glyph(&charmap.into()).await?;
+        Ok(characters::CustomFont5x10::ALL[slot])
+    }
+
+    /// Prints `string` with `attr` drawn in software: the bitmap of every character is taken from `font`,
+    /// transformed and shown through a custom character from `alloc_char`, equal characters share one.
+    /// Characters `font` has no bitmap for are printed plainly. Up to four distinct styled characters
+    /// fit on the display, `CgramFull` means more are needed. Printing the text again with
+    /// `TextAttr::Normal` restores it and lets its custom characters be reused.
+    pub async fn print_styled(&mut self, string:&str, attr:characters::TextAttr, font:&impl characters::GlyphSource<10>) -> Result<&mut Self, Hd44780Error<INTERFACE::Error>> {
+        self.write_styled(string, attr, font).await?;
+        Ok(self)
+    }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use interface::{InterfaceError, InterfaceTrait};
+
+    // display which accepts everything and reads back zeros
+    struct Mock;
+
+    impl InterfaceTrait for Mock {
+        type Error = ();
+
+        fn init(&mut self, _fnset_lines:FnsetLines, _fnset_font:FnsetFont) -> Result<(), InterfaceError<()>> {
+            Ok(())
+        }
+
+        fn send_byte<const RS_VAL:bool>(&mut self, _byte:u8) -> Result<(), InterfaceError<()>> {
+            Ok(())
+        }
+
+        fn receive_byte<const RS_VAL:bool>(&mut self, byte:&mut u8) -> Result<(), InterfaceError<()>> {
+            *byte = 0;
+            Ok(())
+        }
+
+        fn delay_us(&mut self, _us:u32) {}
+
+        fn backlight(&mut self, _bl:bool) -> Result<(), InterfaceError<()>> {
+            Ok(())
+        }
+    }
+
+    fn display() -> Hd44780<Mock, types::DisplayTypeFont5x8> {
+        Hd44780::new(Mock, types::DisplayTypeFont5x8::new(2, 16, FnsetLines::Two))
+    }
+
+    #[test]
+    fn alloc_char_shares_equal_glyphs() {
+        let mut dp = display();
+        let first = dp.alloc_char([1; 8]).unwrap();
+        assert_eq!(dp.alloc_char([2; 8]).unwrap(), characters::CustomFont5x8::Char1);
+        assert_eq!(dp.alloc_char([1; 8]).unwrap(), first);
+    }
+
+    #[test]
+    fn alloc_char_fails_once_all_slots_are_shown() {
+        let mut dp = display();
+        let mut codes = [0u8; 8];
+        for (i, code) in codes.iter_mut().enumerate() {
+            *code = dp.alloc_char([i as u8 + 1; 8]).unwrap() as u8;
+        }
+        // codes handed out before printing are kept
+        assert!(matches!(dp.alloc_char([9; 8]), Err(Hd44780Error::CgramFull)));
+        dp.print_bytes(&codes).unwrap();
+        assert!(matches!(dp.alloc_char([9; 8]), Err(Hd44780Error::CgramFull)));
+
+        // the least recently requested slot is reused once nothing is shown
+        dp.alloc_char([1; 8]).unwrap();
+        dp.clear().unwrap();
+        assert_eq!(dp.alloc_char([9; 8]).unwrap() as u8, codes[1]);
+    }
+
+    #[test]
+    fn load_charset_is_forgotten_by_create_char() {
+        let mut dp = display();
+        let set = characters::CharSet::new("test", &[[1; 8], [2; 8]]);
+        dp.load_charset(&set).unwrap();
+        assert_eq!(dp.charset(), Some("test"));
+        assert_eq!(dp.alloc_char([2; 8]).unwrap(), characters::CustomFont5x8::Char1);
+        dp.create_char(characters::CustomFont5x8::Char7, [3; 8]).unwrap();
+        assert_eq!(dp.charset(), None);
+    }
+
+    #[test]
+    #[cfg(feature="async")]
+    fn fmt_buffer_continues_where_the_last_chunk_ended() {
+        // 121 bytes of padding and 'é', then 20 two byte chars
+        let args = format_args!("{:>120}{:ü<20}", "é", "");
+        let mut data = [0; FMT_BUFFER_SIZE];
+        let mut buf = Buffer::new(&mut data, 0);
+        assert!(buf.write_fmt(args).is_err() && buf.full);
+        // the chunk ends before the char which does not fit any more
+        assert_eq!((buf.len, buf.as_str().chars().last()), (127, Some('ü')));
+
+        let mut data = [0; FMT_BUFFER_SIZE];
+        let mut buf = Buffer::new(&mut data, 127);
+        assert!(buf.write_fmt(args).is_ok() && !buf.full);
+        assert_eq!(buf.as_str().chars().filter(|&c| c == 'ü').count(), 17);
+        assert_eq!(buf.len, 34);
+    }
+
+    #[test]
+    fn rom_fallback_draws_missing_chars_until_all_slots_are_shown() {
+        let mut dp = display();
+        dp.character_rom(Some(characters::Rom::A00)).rom_fallback(true);
+        let shown = |dp:&Hd44780<Mock, types::DisplayTypeFont5x8>| dp.ddram.shown_slots(FnsetFont::Dots5x8).iter().filter(|&&shown| shown).count();
+        dp.print_string("Ж").unwrap();
+        assert_eq!(shown(&dp), 1);
+        // a glyph which is loaded already keeps its slot
+        dp.print_string("Ж").unwrap();
+        assert_eq!(shown(&dp), 1);
+
+        dp.print_string("БГДЗИЙЛ").unwrap();
+        assert_eq!(shown(&dp), 8);
+        assert_eq!(dp.substitute(characters::Rom::A00, 'П').unwrap(), b'?');
+        dp.clear().unwrap();
+        assert_eq!(dp.substitute(characters::Rom::A00, 'П').unwrap(), characters::CustomFont5x8::Char0 as u8);
+    }
+}
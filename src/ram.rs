@@ -0,0 +1,266 @@
+
+// Address counter tracking and software copies of the display RAMs, shared by the blocking
+// and the async driver. The copies let the driver know which custom characters are loaded
+// and shown without reading the display.
+
+use crate::{
+    framebuffer::MAX_CELLS,
+    instructions::{FnsetFont, FnsetLines},
+};
+
+
+const SLOTS: usize = 8;
+const MAX_ROWS: usize = 10;
+
+
+// Address counter after `count` DD RAM accesses starting at `address`. It cycles through all 80 cells,
+// on two line displays jumping from 0x27 to 0x40 and from 0x67 back to 0x00.
+pub(crate) fn advance(address:u8, count:usize, inc:bool, lines:FnsetLines) -> u8 {
+    let step = count % MAX_CELLS;
+    let index = index(address, lines);
+    let index = if inc { (index + step) % MAX_CELLS } else { (index + MAX_CELLS - step) % MAX_CELLS };
+    match lines {
+        FnsetLines::Two if index >= MAX_CELLS / 2 => 0x40 + (index - MAX_CELLS / 2) as u8,
+        _ => index as u8,
+    }
+}
+
+// position of `address` in the cycle of the address counter
+fn index(address:u8, lines:FnsetLines) -> usize {
+    let index = match lines {
+        FnsetLines::Two if address >= 0x40 => MAX_CELLS / 2 + (address - 0x40) as usize,
+        _ => address as usize,
+    };
+    index % MAX_CELLS
+}
+
+// number of custom characters, a 5x10 glyph takes up two 5x8 slots
+pub(crate) fn slots(font:FnsetFont) -> usize {
+    match font {
+        FnsetFont::Dots5x8 => SLOTS,
+        FnsetFont::Dots5x10 => SLOTS / 2,
+    }
+}
+
+// slot shown by the character `code`, `None` for ROM characters
+pub(crate) fn slot(code:u8, font:FnsetFont) -> Option<usize> {
+    match (code, font) {
+        (0x10.., _) => None,
+        (_, FnsetFont::Dots5x8) => Some((code & 0b111) as usize),
+        (_, FnsetFont::Dots5x10) => Some(((code >> 1) & 0b11) as usize),
+    }
+}
+
+// pixel rows of a custom character
+pub(crate) fn height(font:FnsetFont) -> usize {
+    match font {
+        FnsetFont::Dots5x8 => 8,
+        FnsetFont::Dots5x10 => MAX_ROWS,
+    }
+}
+
+// character code showing `slot`
+pub(crate) fn code(slot:usize, font:FnsetFont) -> u8 {
+    match font {
+        FnsetFont::Dots5x8 => 0b1000 | slot as u8,
+        FnsetFont::Dots5x10 => 0b1001 | (slot as u8) << 1,
+    }
+}
+
+// CG RAM address of the first row of `slot`
+pub(crate) fn address(slot:usize, font:FnsetFont) -> u8 {
+    match font {
+        FnsetFont::Dots5x8 => (slot as u8) << 3,
+        FnsetFont::Dots5x10 => (slot as u8) << 4,
+    }
+}
+
+
+// Characters written to DD RAM, in address counter order.
+pub(crate) struct Ddram {
+    cells: [u8; MAX_CELLS],
+}
+
+impl Ddram {
+    pub(crate) const fn new() -> Self {
+        Self { cells: [b' '; MAX_CELLS] }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.cells = [b' '; MAX_CELLS];
+    }
+
+    // records `bytes` written starting at `address`
+    pub(crate) fn write(&mut self, address:u8, bytes:&[u8], inc:bool, lines:FnsetLines) {
+        let mut address = address;
+        for &byte in bytes {
+            self.cells[index(address, lines)] = byte;
+            address = advance(address, 1, inc, lines);
+        }
+    }
+
+    // slots which are shown somewhere in DD RAM
+    pub(crate) fn shown_slots(&self, font:FnsetFont) -> [bool; SLOTS] {
+        let mut shown = [false; SLOTS];
+        for &code in self.cells.iter() {
+            if let Some(slot) = slot(code, font) {
+                shown[slot] = true;
+            }
+        }
+        shown
+    }
+}
+
+
+#[derive(Copy, Clone)]
+struct Slot {
+    rows: [u8; MAX_ROWS],
+    loaded: bool,
+    used: u32,
+}
+
+// Glyphs loaded into CG RAM through the driver, with the time they were last asked for.
+pub(crate) struct Cgram {
+    slots: [Slot; SLOTS],
+    clock: u32,
+    // slots handed out since the last DD RAM write, which are about to be shown
+    pending: u8,
+}
+
+impl Cgram {
+    pub(crate) const fn new() -> Self {
+        Self {
+            slots: [Slot { rows: [0; MAX_ROWS], loaded: false, used: 0 }; SLOTS],
+            clock: 0,
+            pending: 0,
+        }
+    }
+
+    fn tick(&mut self) -> u32 {
+        self.clock = self.clock.wrapping_add(1);
+        self.clock
+    }
+
+    fn matches(slot:&Slot, rows:&[u8]) -> bool {
+        slot.loaded && slot.rows.iter().zip(rows).all(|(a, b)| a & 0x1f == b & 0x1f)
+    }
+
+    pub(crate) fn store(&mut self, slot:usize, rows:&[u8]) {
+        let used = self.tick();
+        let entry = &mut self.slots[slot];
+        entry.rows = [0; MAX_ROWS];
+        entry.rows[..rows.len()].copy_from_slice(rows);
+        entry.loaded = true;
+        entry.used = used;
+    }
+
+    // slot already holding `rows`, which then counts as used
+    pub(crate) fn find(&mut self, rows:&[u8], font:FnsetFont) -> Option<usize> {
+        let slot = self.slots[..slots(font)].iter().position(|slot| Self::matches(slot, rows))?;
+        self.slots[slot].used = self.tick();
+        Some(slot)
+    }
+
+    // keeps `slot` from being reused until the next DD RAM write
+    pub(crate) fn reserve(&mut self, slot:usize) {
+        self.pending |= 1 << slot;
+    }
+
+    pub(crate) fn release(&mut self) {
+        self.pending = 0;
+    }
+
+    // slot to load a new glyph into: an empty one, else the least recently used one which is neither shown nor reserved
+    pub(crate) fn victim(&self, font:FnsetFont, shown:&[bool; SLOTS]) -> Option<usize> {
+        let slots = &self.slots[..slots(font)];
+        slots.iter().position(|slot| !slot.loaded).or_else(|| {
+            slots.iter().enumerate()
+                .filter(|(i, _)| !shown[*i] && self.pending & (1 << i) == 0)
+                .max_by_key(|(_, slot)| self.clock.wrapping_sub(slot.used))
+                .map(|(i, _)| i)
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advance_jumps_between_lines() {
+        assert_eq!(advance(0x10, 3, true, FnsetLines::Two), 0x13);
+        assert_eq!(advance(0x27, 1, true, FnsetLines::Two), 0x40);
+        assert_eq!(advance(0x67, 1, true, FnsetLines::Two), 0x00);
+        assert_eq!(advance(0x26, 3, true, FnsetLines::Two), 0x41);
+        assert_eq!(advance(0x4f, 1, true, FnsetLines::One), 0x00);
+    }
+
+    #[test]
+    fn advance_decrements() {
+        assert_eq!(advance(0x13, 3, false, FnsetLines::Two), 0x10);
+        assert_eq!(advance(0x40, 1, false, FnsetLines::Two), 0x27);
+        assert_eq!(advance(0x00, 1, false, FnsetLines::Two), 0x67);
+        assert_eq!(advance(0x00, 1, false, FnsetLines::One), 0x4f);
+        assert_eq!(advance(0x05, MAX_CELLS, false, FnsetLines::Two), 0x05);
+    }
+
+    fn filled() -> Cgram {
+        let mut cgram = Cgram::new();
+        for slot in 0..SLOTS {
+            cgram.store(slot, &[slot as u8 + 1; 8]);
+        }
+        cgram
+    }
+
+    #[test]
+    fn victim_takes_empty_slots_first() {
+        let mut cgram = Cgram::new();
+        assert_eq!(cgram.victim(FnsetFont::Dots5x8, &[false; SLOTS]), Some(0));
+        cgram.store(0, &[1; 8]);
+        assert_eq!(cgram.victim(FnsetFont::Dots5x8, &[false; SLOTS]), Some(1));
+    }
+
+    #[test]
+    fn victim_is_least_recently_used() {
+        let mut cgram = filled();
+        assert_eq!(cgram.victim(FnsetFont::Dots5x8, &[false; SLOTS]), Some(0));
+        assert_eq!(cgram.find(&[1; 8], FnsetFont::Dots5x8), Some(0));
+        assert_eq!(cgram.victim(FnsetFont::Dots5x8, &[false; SLOTS]), Some(1));
+        cgram.store(1, &[9; 8]);
+        assert_eq!(cgram.victim(FnsetFont::Dots5x8, &[false; SLOTS]), Some(2));
+    }
+
+    #[test]
+    fn victim_skips_shown_and_pending_slots() {
+        let mut cgram = filled();
+        let mut shown = [false; SLOTS];
+        shown[0] = true;
+        assert_eq!(cgram.victim(FnsetFont::Dots5x8, &shown), Some(1));
+        cgram.reserve(1);
+        assert_eq!(cgram.victim(FnsetFont::Dots5x8, &shown), Some(2));
+        cgram.release();
+        assert_eq!(cgram.victim(FnsetFont::Dots5x8, &shown), Some(1));
+        assert_eq!(cgram.victim(FnsetFont::Dots5x8, &[true; SLOTS]), None);
+    }
+
+    #[test]
+    fn find_matches_the_pixels_of_the_font() {
+        let mut cgram = filled();
+        assert_eq!(cgram.find(&[0xe3; 8], FnsetFont::Dots5x8), Some(2));
+        assert_eq!(cgram.find(&[5; 8], FnsetFont::Dots5x8), Some(4));
+        assert_eq!(cgram.find(&[5; 8], FnsetFont::Dots5x10), None);
+        assert_eq!(cgram.find(&[10; 8], FnsetFont::Dots5x8), None);
+    }
+
+    #[test]
+    fn shown_slots_follow_ddram() {
+        let mut ddram = Ddram::new();
+        ddram.write(0x27, &[0x08, 0x0b, b'A'], true, FnsetLines::Two);
+        let shown = ddram.shown_slots(FnsetFont::Dots5x8);
+        assert_eq!(shown, [true, false, false, true, false, false, false, false]);
+        assert_eq!(ddram.shown_slots(FnsetFont::Dots5x10)[..4], [true, true, false, false]);
+        ddram.clear();
+        assert_eq!(ddram.shown_slots(FnsetFont::Dots5x8), [false; SLOTS]);
+    }
+}
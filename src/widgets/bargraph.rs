@@ -0,0 +1,142 @@
+
+use crate::{
+    Hd44780, Hd44780Error,
+    characters::{CharSet, CustomFont5x8},
+    framebuffer::{MAX_CELLS, flush_row},
+    interface::InterfaceTrait,
+    types::{DisplayTypeFont5x8, DisplayTypeTrait},
+};
+
+#[cfg(feature="async")]
+use crate::{Hd44780Async, framebuffer::flush_row_async, interface::AsyncInterfaceTrait};
+
+
+// glyph `i` fills the bottom `i + 1` rows of a cell
+const fn levels() -> CharSet {
+    let mut glyphs = [[0; 8]; 8];
+    let mut level = 0;
+    while level < 8 {
+        let mut y = 7 - level;
+        while y < 8 {
+            glyphs[level][y] = 0x1f;
+            y += 1;
+        }
+        level += 1;
+    }
+    CharSet::new("bar graph", &glyphs)
+}
+
+const LEVELS: CharSet = levels();
+
+
+/// Column chart with one bar per display column, growing from the bottom row up.
+/// Every cell shows one of eight fill levels, which are loaded as a [`CharSet`] taking up
+/// all custom characters. Only cells which changed since the last update are rewritten.
+#[derive(Debug, Copy, Clone)]
+pub struct BarGraph {
+    row: u8,
+    col: u8,
+    width: u8,
+    height: u8,
+    // filled pixel rows of every bar as shown on the display
+    shown: [u8; MAX_CELLS],
+    synced: bool,
+}
+
+impl BarGraph {
+    /// Graph of `width` bars which are `height` rows high, its top left corner is at `row`/`col`.
+    pub fn new(row:u8, col:u8, width:u8, height:u8) -> Self {
+        Self { row, col, width, height, shown: [0; MAX_CELLS], synced: false }
+    }
+
+    /// Makes the next update redraw all bars.
+    pub fn invalidate(&mut self) -> &mut Self {
+        self.synced = false;
+        self
+    }
+
+    /// The custom characters used by the graph.
+    pub const fn charset() -> &'static CharSet {
+        &LEVELS
+    }
+
+    // visible part of the graph as (width, height)
+    fn size<DPTYPE: DisplayTypeTrait>(&self, dp_type:&DPTYPE) -> (u8, u8) {
+        let width = self.width.min(dp_type.cols().saturating_sub(self.col)).min(MAX_CELLS as u8);
+        let height = self.height.min(dp_type.rows().saturating_sub(self.row)).min(MAX_CELLS as u8 / 8);
+        (width, height)
+    }
+
+    fn pixels(value:u32, scale:u32, height:u8) -> u8 {
+        if scale == 0 {
+            return 0;
+        }
+        (value.min(scale) as u64 * height as u64 * 8 / scale as u64) as u8
+    }
+
+    // character code of the cell `row` rows below the top of a bar with `pixels` filled rows
+    fn cell(pixels:u8, row:u8, height:u8) -> u8 {
+        let bottom = (height - 1 - row) * 8;
+        match pixels.saturating_sub(bottom).min(8) {
+            0 => b' ',
+            level => CustomFont5x8::ALL[level as usize - 1] as u8,
+        }
+    }
+
+    // character codes of display row `row` of the graph
+    fn codes(pixels:&[u8], row:u8, height:u8) -> [u8; MAX_CELLS] {
+        let mut codes = [b' '; MAX_CELLS];
+        for (code, &pixels) in codes.iter_mut().zip(pixels) {
+            *code = Self::cell(pixels, row, height);
+        }
+        codes
+    }
+
+    fn changed(&self, bar:u8, pixels:u8, row:u8, height:u8) -> bool {
+        !self.synced || Self::cell(pixels, row, height) != Self::cell(self.shown[bar as usize], row, height)
+    }
+
+    /// Shows one bar per entry of `values`, a value of `scale` or above fills the whole height.
+    /// Bars without a value are empty, values beyond the width are ignored.
+    pub fn update<INTERFACE>(&mut self, dp:&mut Hd44780<INTERFACE, DisplayTypeFont5x8>, values:&[u32], scale:u32) -> Result<(), Hd44780Error<INTERFACE::Error>>
+    where
+        INTERFACE: InterfaceTrait,
+    {
+        let (width, height) = self.size(dp.display_type());
+        let mut pixels = [0u8; MAX_CELLS];
+        for (bar, &value) in pixels.iter_mut().zip(values.iter().take(width as usize)) {
+            *bar = Self::pixels(value, scale, height);
+        }
+
+        dp.load_charset(&LEVELS)?;
+        for row in 0..height {
+            let codes = Self::codes(&pixels[..width as usize], row, height);
+            flush_row(dp, self.row + row, self.col, &codes[..width as usize], |bar| self.changed(bar as u8, pixels[bar], row, height))?;
+        }
+        self.shown = pixels;
+        self.synced = true;
+        Ok(())
+    }
+
+    /// Async counterpart of [`BarGraph::update`].
+    #[cfg(feature="async")]
+    pub async fn update_async<INTERFACE>(&mut self, dp:&mut Hd44780Async<INTERFACE, DisplayTypeFont5x8>, values:&[u32], scale:u32) -> Result<(), Hd44780Error<INTERFACE::Error>>
+    where
+        INTERFACE: AsyncInterfaceTrait,
+    {
+        let (width, height) = self.size(dp.display_type());
+        let mut pixels = [0u8; MAX_CELLS];
+        for (bar, &value) in pixels.iter_mut().zip(values.iter().take(width as usize)) {
+            *bar = Self::pixels(value, scale, height);
+        }
+
+        dp.load_charset(&LEVELS).await?;
+        for row in 0..height {
+            let codes = Self::codes(&pixels[..width as usize], row, height);
+            flush_row_async(dp, self.row + row, self.col, &codes[..width as usize], |bar| self.changed(bar as u8, pixels[bar], row, height)).await?;
+        }
+        self.shown = pixels;
+        self.synced = true;
+        Ok(())
+    }
+}
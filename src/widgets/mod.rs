@@ -0,0 +1,7 @@
+
+// Widgets drawing with custom characters, each owns the CG RAM while it is shown.
+// All of them only rewrite the cells which changed.
+
+pub mod progress;
+pub mod bargraph;
+pub mod sparkline;
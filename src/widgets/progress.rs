@@ -0,0 +1,191 @@
+
+use crate::{
+    Hd44780, Hd44780Error,
+    characters::{CharSet, CustomFont5x8},
+    framebuffer::{MAX_CELLS, flush_row},
+    interface::InterfaceTrait,
+    types::{DisplayTypeFont5x8, DisplayTypeTrait},
+};
+
+#[cfg(feature="async")]
+use crate::{Hd44780Async, framebuffer::flush_row_async, interface::AsyncInterfaceTrait};
+
+
+const LABEL_WIDTH: u8 = 4;
+
+
+// bar cell with `level` filled pixel columns, the caps frame it at the top and bottom
+// which leaves room for 4 filled rows
+const fn level(level:u8, caps:bool) -> [u8; 8] {
+    let (fill, frame) = if caps { (0b0011_1100u8, 0b1000_0001u8) } else { (0b0111_1110, 0) };
+    let pattern = !(0x1f >> level) & 0x1f;
+    let mut rows = [0; 8];
+    let mut y = 0;
+    while y < 8 {
+        rows[y] = if frame & (0x80 >> y) != 0 { 0x1f } else if fill & (0x80 >> y) != 0 { pattern } else { 0 };
+        y += 1;
+    }
+    rows
+}
+
+const LEFT_CAP: [u8; 8] = [0b00011, 0b00010, 0b00010, 0b00010, 0b00010, 0b00010, 0b00010, 0b00011];
+const RIGHT_CAP: [u8; 8] = [0b11000, 0b01000, 0b01000, 0b01000, 0b01000, 0b01000, 0b01000, 0b11000];
+
+// glyph `i` shows level `i + 1`, an empty cell is a space
+const PLAIN: CharSet = CharSet::new("progress bar", &[level(1, false), level(2, false), level(3, false), level(4, false), level(5, false)]);
+// glyph `i` shows level `i`, followed by the caps
+const CAPPED: CharSet = CharSet::new("progress bar with caps", &[
+    level(0, true), level(1, true), level(2, true), level(3, true), level(4, true), level(5, true), LEFT_CAP, RIGHT_CAP,
+]);
+
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Cell {
+    // filled columns of a bar cell, 0 to 5
+    Level(u8),
+    LeftCap,
+    RightCap,
+    Char(u8),
+}
+
+
+/// Horizontal bar filling one pixel column at a time, 5 per display cell.
+/// Optional end caps draw a frame around the bar, the optional label shows the percentage
+/// right of it (e.g. `" 42%"`). The bar remembers what it has drawn and only rewrites changed cells.
+/// Its glyphs are loaded as a [`CharSet`] into the first five custom characters, or all eight with caps.
+#[derive(Debug, Copy, Clone)]
+pub struct ProgressBar {
+    row: u8,
+    col: u8,
+    width: u8,
+    caps: bool,
+    label: bool,
+    // filled pixel columns and label of the last update
+    shown: Option<(u16, [u8; LABEL_WIDTH as usize])>,
+}
+
+impl ProgressBar {
+    /// Bar taking up `width` cells from `row`/`col` on, including caps and label.
+    pub fn new(row:u8, col:u8, width:u8) -> Self {
+        Self { row, col, width, caps: false, label: false, shown: None }
+    }
+
+    pub fn with_caps(mut self) -> Self {
+        self.caps = true;
+        self.shown = None;
+        self
+    }
+
+    pub fn with_label(mut self) -> Self {
+        self.label = true;
+        self.shown = None;
+        self
+    }
+
+    /// Makes the next update redraw the whole bar, e.g. after the display was cleared.
+    pub fn invalidate(&mut self) -> &mut Self {
+        self.shown = None;
+        self
+    }
+
+    fn bar_cells(&self) -> u8 {
+        let label = if self.label { LABEL_WIDTH } else { 0 };
+        let caps = if self.caps { 2 } else { 0 };
+        self.width.saturating_sub(label + caps)
+    }
+
+    fn pixels(&self, value:u32, max:u32) -> u16 {
+        let total = self.bar_cells() as u64 * 5;
+        if max == 0 {
+            return 0;
+        }
+        (value.min(max) as u64 * total / max as u64) as u16
+    }
+
+    fn label(value:u32, max:u32) -> [u8; LABEL_WIDTH as usize] {
+        let percent = if max == 0 { 0 } else { value.min(max) as u64 * 100 / max as u64 } as u8;
+        let digit = |d:u8, show:bool| if show { b'0' + d } else { b' ' };
+        [digit(percent / 100, percent >= 100), digit(percent / 10 % 10, percent >= 10), b'0' + percent % 10, b'%']
+    }
+
+    fn cell(&self, index:u8, pixels:u16, label:&[u8; LABEL_WIDTH as usize]) -> Cell {
+        let bar = self.bar_cells();
+        let caps = self.caps as u8;
+        if self.caps && index == 0 {
+            Cell::LeftCap
+        } else if self.caps && index == bar + 1 {
+            Cell::RightCap
+        } else if index < bar + caps {
+            let level = pixels.saturating_sub((index - caps) as u16 * 5).min(5);
+            Cell::Level(level as u8)
+        } else {
+            Cell::Char(label[(index - bar - 2 * caps) as usize])
+        }
+    }
+
+    /// The custom characters used by the bar.
+    pub const fn charset(&self) -> &'static CharSet {
+        if self.caps { &CAPPED } else { &PLAIN }
+    }
+
+    fn code(&self, cell:Cell) -> u8 {
+        let glyph = |index:u8| CustomFont5x8::ALL[index as usize] as u8;
+        match cell {
+            Cell::Level(0) if !self.caps => b' ',
+            Cell::Level(level) if !self.caps => glyph(level - 1),
+            Cell::Level(level) => glyph(level),
+            Cell::LeftCap => glyph(6),
+            Cell::RightCap => glyph(7),
+            Cell::Char(c) => c,
+        }
+    }
+
+    // character codes of the first `width` cells
+    fn codes(&self, width:u8, pixels:u16, label:&[u8; LABEL_WIDTH as usize]) -> [u8; MAX_CELLS] {
+        let mut codes = [b' '; MAX_CELLS];
+        for index in 0..width {
+            codes[index as usize] = self.code(self.cell(index, pixels, label));
+        }
+        codes
+    }
+
+    fn width<DPTYPE: DisplayTypeTrait>(&self, dp_type:&DPTYPE) -> u8 {
+        self.width.min(dp_type.cols().saturating_sub(self.col)).min(MAX_CELLS as u8)
+    }
+
+    fn changed(&self, index:u8, pixels:u16, label:&[u8; LABEL_WIDTH as usize]) -> bool {
+        match &self.shown {
+            Some((shown_pixels, shown_label)) => self.cell(index, pixels, label) != self.cell(index, *shown_pixels, shown_label),
+            None => true,
+        }
+    }
+
+    /// Shows `value` out of `max`, rewriting only the cells which differ from the last update.
+    pub fn update<INTERFACE>(&mut self, dp:&mut Hd44780<INTERFACE, DisplayTypeFont5x8>, value:u32, max:u32) -> Result<(), Hd44780Error<INTERFACE::Error>>
+    where
+        INTERFACE: InterfaceTrait,
+    {
+        let (pixels, label) = (self.pixels(value, max), Self::label(value, max));
+        let width = self.width(dp.display_type());
+        dp.load_charset(self.charset())?;
+        let codes = self.codes(width, pixels, &label);
+        flush_row(dp, self.row, self.col, &codes[..width as usize], |index| self.changed(index as u8, pixels, &label))?;
+        self.shown = Some((pixels, label));
+        Ok(())
+    }
+
+    /// Async counterpart of [`ProgressBar::update`].
+    #[cfg(feature="async")]
+    pub async fn update_async<INTERFACE>(&mut self, dp:&mut Hd44780Async<INTERFACE, DisplayTypeFont5x8>, value:u32, max:u32) -> Result<(), Hd44780Error<INTERFACE::Error>>
+    where
+        INTERFACE: AsyncInterfaceTrait,
+    {
+        let (pixels, label) = (self.pixels(value, max), Self::label(value, max));
+        let width = self.width(dp.display_type());
+        dp.load_charset(self.charset()).await?;
+        let codes = self.codes(width, pixels, &label);
+        flush_row_async(dp, self.row, self.col, &codes[..width as usize], |index| self.changed(index as u8, pixels, &label)).await?;
+        self.shown = Some((pixels, label));
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+
+use crate::{
+    Hd44780, Hd44780Error,
+    characters::CustomFont5x8,
+    interface::InterfaceTrait,
+    types::DisplayTypeFont5x8,
+};
+
+#[cfg(feature="async")]
+use crate::{Hd44780Async, interface::AsyncInterfaceTrait};
+
+
+const CELLS: usize = 8;
+const MAX_SAMPLES: usize = CELLS * 5;
+
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SparklineStyle {
+    Line,
+    Area,
+}
+
+/// Trend chart of the latest samples, one per pixel column, the newest on the right.
+/// It treats up to 8 cells as a pixel strip: one row of up to 8 cells (40x8 pixels)
+/// or two rows of up to 4 cells (20x16 pixels). Cell `i` (left to right, top to bottom) is drawn
+/// by `CustomFont5x8::ALL[i]`, only glyphs which changed are uploaded again.
+/// The chart owns these custom characters while it is shown: it does not notice when something else
+/// writes to them, call `invalidate` before the next update if anything did.
+#[derive(Debug, Copy, Clone)]
+pub struct Sparkline {
+    row: u8,
+    col: u8,
+    cols: u8,
+    rows: u8,
+    scale: u32,
+    style: SparklineStyle,
+    // pixel height of every sample, oldest first
+    samples: [u8; MAX_SAMPLES],
+    len: usize,
+    glyphs: [[u8; 8]; CELLS],
+    synced: bool,
+}
+
+impl Sparkline {
+    /// Chart of `cols` x `rows` cells at `row`/`col`, a sample of `scale` or above reaches the top.
+    /// `rows` is limited to 2 and `cols` to the cells left for that many rows.
+    pub fn new(row:u8, col:u8, cols:u8, rows:u8, scale:u32) -> Self {
+        let rows = rows.clamp(1, 2);
+        let cols = cols.min(CELLS as u8 / rows);
+        Self {
+            row, col, cols, rows, scale,
+            style: SparklineStyle::Line,
+            samples: [0; MAX_SAMPLES],
+            len: 0,
+            glyphs: [[0; 8]; CELLS],
+            synced: false,
+        }
+    }
+
+    /// Draws the samples as a connected line (default) or filled below.
+    pub fn with_style(mut self, style:SparklineStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Makes the next update upload every glyph and print the cells again.
+    pub fn invalidate(&mut self) -> &mut Self {
+        self.synced = false;
+        self
+    }
+
+    /// Drops all samples.
+    pub fn clear(&mut self) -> &mut Self {
+        self.len = 0;
+        self
+    }
+
+    fn width(&self) -> usize {
+        self.cols as usize * 5
+    }
+
+    fn height(&self) -> u8 {
+        self.rows * 8
+    }
+
+    /// Adds the newest sample, the oldest one scrolls out once the chart is full.
+    pub fn push(&mut self, sample:u32) -> &mut Self {
+        if self.width() == 0 {
+            return self;
+        }
+        let top = self.height() as u64 - 1;
+        let y = if self.scale == 0 { 0 } else { sample.min(self.scale) as u64 * top / self.scale as u64 } as u8;
+        if self.len == self.width() {
+            self.samples.copy_within(1..self.len, 0);
+            self.len -= 1;
+        }
+        self.samples[self.len] = y;
+        self.len += 1;
+        self
+    }
+
+    fn set(&self, glyphs:&mut [[u8; 8]; CELLS], x:usize, y:u8) {
+        let cell_row = (self.rows - 1 - y / 8) as usize;
+        let cell = cell_row * self.cols as usize + x / 5;
+        glyphs[cell][7 - (y % 8) as usize] |= 0x10 >> (x % 5);
+    }
+
+    fn render(&self) -> [[u8; 8]; CELLS] {
+        let mut glyphs = [[0; 8]; CELLS];
+        let offset = self.width() - self.len;
+        for (i, &y) in self.samples[..self.len].iter().enumerate() {
+            let (low, high) = match self.style {
+                SparklineStyle::Area => (0, y),
+                // joins the sample to the previous one with a vertical run
+                SparklineStyle::Line => {
+                    let previous = if i > 0 { self.samples[i - 1] } else { y };
+                    (y.min(previous), y.max(previous))
+                },
+            };
+            for y in low..=high {
+                self.set(&mut glyphs, offset + i, y);
+            }
+        }
+        glyphs
+    }
+
+    /// Uploads the glyphs which changed since the last update, the cells are only printed
+    /// on the first update or after `invalidate`.
+    pub fn update<INTERFACE>(&mut self, dp:&mut Hd44780<INTERFACE, DisplayTypeFont5x8>) -> Result<(), Hd44780Error<INTERFACE::Error>>
+    where
+        INTERFACE: InterfaceTrait,
+    {
+        let glyphs = self.render();
+        let cells = (self.cols * self.rows) as usize;
+        for (cell, (&glyph, shown)) in glyphs.iter().zip(self.glyphs.iter()).take(cells).enumerate() {
+            if !self.synced || glyph != *shown {
+                dp.create_char(CustomFont5x8::ALL[cell], glyph)?;
+            }
+        }
+        self.glyphs = glyphs;
+
+        if !self.synced {
+            for row in 0..self.rows {
+                let codes = CustomFont5x8::ALL.map(|code| code as u8);
+                let first = (row * self.cols) as usize;
+                dp.position(self.row + row, self.col)?;
+                dp.print_bytes(&codes[first..first + self.cols as usize])?;
+            }
+            self.synced = true;
+        }
+        Ok(())
+    }
+
+    /// Async counterpart of [`Sparkline::update`].
+    #[cfg(feature="async")]
+    pub async fn update_async<INTERFACE>(&mut self, dp:&mut Hd44780Async<INTERFACE, DisplayTypeFont5x8>) -> Result<(), Hd44780Error<INTERFACE::Error>>
+    where
+        INTERFACE: AsyncInterfaceTrait,
+    {
+        let glyphs = self.render();
+        let cells = (self.cols * self.rows) as usize;
+        for (cell, (&glyph, shown)) in glyphs.iter().zip(self.glyphs.iter()).take(cells).enumerate() {
+            if !self.synced || glyph != *shown {
+                dp.create_char(CustomFont5x8::ALL[cell], glyph).await?;
+            }
+        }
+        self.glyphs = glyphs;
+
+        if !self.synced {
+            for row in 0..self.rows {
+                let codes = CustomFont5x8::ALL.map(|code| code as u8);
+                let first = (row * self.cols) as usize;
+                dp.position(self.row + row, self.col).await?;
+                dp.print_bytes(&codes[first..first + self.cols as usize]).await?;
+            }
+            self.synced = true;
+        }
+        Ok(())
+    }
+}